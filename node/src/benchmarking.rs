@@ -122,6 +122,7 @@ pub fn create_benchmark_extrinsic(
 		frame_system::CheckWeight::<runtime::Runtime>::new(),
 		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(0),
 		frame_metadata_hash_extension::CheckMetadataHash::<runtime::Runtime>::new(false),
+		pallet_pocs::RecordCallFailure::<runtime::Runtime>::new(),
 		frame_system::WeightReclaim::<runtime::Runtime>::new(),
 	);
 
@@ -139,6 +140,7 @@ pub fn create_benchmark_extrinsic(
 			(),
 			None,
 			(),
+			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
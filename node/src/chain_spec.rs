@@ -1,5 +1,5 @@
 use sc_service::ChainType;
-use substrate_aura_runtime::WASM_BINARY;
+use substrate_aura_runtime::{genesis_config_presets::POCS_DEMO_RUNTIME_PRESET, WASM_BINARY};
 
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
 pub type ChainSpec = sc_service::GenericChainSpec;
@@ -27,3 +27,15 @@ pub fn local_chain_spec() -> Result<ChainSpec, String> {
 	.with_genesis_config_preset_name(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET)
 	.build())
 }
+
+pub fn pocs_demo_chain_spec() -> Result<ChainSpec, String> {
+	Ok(ChainSpec::builder(
+		WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
+		None,
+	)
+	.with_name("PoCS Demo")
+	.with_id("pocs_demo")
+	.with_chain_type(ChainType::Development)
+	.with_genesis_config_preset_name(POCS_DEMO_RUNTIME_PRESET)
+	.build())
+}
@@ -7,6 +7,39 @@ pub struct Cli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	/// Seal blocks instantly, on demand or every given number of milliseconds instead of
+	/// authoring them with Aura. Intended for development chains only.
+	#[arg(long, value_name = "instant|manual|<ms>")]
+	pub sealing: Option<Sealing>,
+}
+
+/// Block sealing modes that replace Aura block authoring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sealing {
+	/// Seal a block as soon as a transaction enters the pool.
+	Instant,
+	/// Seal blocks on demand through the `engine_createBlock` RPC.
+	Manual,
+	/// Seal a block every given number of milliseconds.
+	Interval(u64),
+}
+
+impl std::str::FromStr for Sealing {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"instant" => Ok(Self::Instant),
+			"manual" => Ok(Self::Manual),
+			millis => match millis.parse() {
+				Ok(millis) if millis > 0 => Ok(Self::Interval(millis)),
+				_ => Err(format!(
+					"invalid sealing `{s}`, expected `instant`, `manual` or a number of milliseconds"
+				)),
+			},
+		}
+	}
 }
 
 #[derive(Debug, clap::Subcommand)]
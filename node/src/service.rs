@@ -1,15 +1,32 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
-use futures::FutureExt;
-use sc_client_api::{Backend, BlockBackend};
+use crate::cli::Sealing;
+use futures::{channel::mpsc, FutureExt, StreamExt};
+use sc_client_api::{Backend, BlockBackend, StorageProvider};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
-use sc_service::{error::Error as ServiceError, Configuration, TaskManager, WarpSyncConfig};
+use sc_consensus_manual_seal::{
+	consensus::aura::AuraConsensusDataProvider, EngineCommand, ManualSealParams,
+};
+use sc_service::{
+	error::Error as ServiceError, ChainType, Configuration, TaskManager, WarpSyncConfig,
+};
 use sc_telemetry::{Telemetry, TelemetryWorker};
-use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sc_transaction_pool_api::{OffchainTransactionPoolFactory, TransactionPool};
+use sp_blockchain::HeaderBackend;
 use substrate_aura_runtime::{self, apis::RuntimeApi, opaque::Block};
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
-use std::{sync::Arc, time::Duration};
+use sp_core::{
+	hashing::twox_128,
+	storage::{StorageData, StorageKey},
+};
+use std::{
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
 
 pub(crate) type FullClient = sc_service::TFullClient<
 	Block,
@@ -36,7 +53,10 @@ pub type Service = sc_service::PartialComponents<
 	),
 >;
 
-pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
+pub fn new_partial(
+	config: &Configuration,
+	sealing: Option<Sealing>,
+) -> Result<Service, ServiceError> {
 	let telemetry = config
 		.telemetry_endpoints
 		.clone()
@@ -83,8 +103,15 @@ pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
 		telemetry.as_ref().map(|x| x.handle()),
 	)?;
 
-	let cidp_client = client.clone();
-	let import_queue =
+	let import_queue = if sealing.is_some() {
+		// Sealed blocks carry no Aura seal, hence they are imported without verification.
+		sc_consensus_manual_seal::import_queue(
+			Box::new(grandpa_block_import.clone()),
+			&task_manager.spawn_essential_handle(),
+			config.prometheus_registry(),
+		)
+	} else {
+		let cidp_client = client.clone();
 		sc_consensus_aura::import_queue::<AuraPair, _, _, _, _, _>(ImportQueueParams {
 			block_import: grandpa_block_import.clone(),
 			justification_import: Some(Box::new(grandpa_block_import.clone())),
@@ -112,7 +139,8 @@ pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
 			check_for_equivocation: Default::default(),
 			telemetry: telemetry.as_ref().map(|x| x.handle()),
 			compatibility_mode: Default::default(),
-		})?;
+		})?
+	};
 
 	Ok(sc_service::PartialComponents {
 		client,
@@ -126,12 +154,35 @@ pub fn new_partial(config: &Configuration) -> Result<Service, ServiceError> {
 	})
 }
 
+/// Returns `Timestamp::now` of the best block, i.e. zero at genesis.
+fn best_timestamp(client: &FullClient) -> Result<u64, ServiceError> {
+	let key = [twox_128(b"Timestamp"), twox_128(b"Now")].concat();
+	let now = client.storage(client.info().best_hash, &StorageKey(key))?;
+	match now {
+		Some(StorageData(now)) => now
+			.try_into()
+			.map(u64::from_le_bytes)
+			.map_err(|_| ServiceError::Other("invalid `Timestamp::now` in the best block".into())),
+		None => Ok(0),
+	}
+}
+
 /// Builds a new service for a full client.
 pub fn new_full<
 	N: sc_network::NetworkBackend<Block, <Block as sp_runtime::traits::Block>::Hash>,
 >(
 	config: Configuration,
+	sealing: Option<Sealing>,
 ) -> Result<TaskManager, ServiceError> {
+	// Sealed blocks bypass Aura, which is only acceptable on chains used for testing.
+	if sealing.is_some() &&
+		!matches!(config.chain_spec.chain_type(), ChainType::Development | ChainType::Local)
+	{
+		return Err(ServiceError::Other(
+			"`--sealing` is only supported on development and local chains".into(),
+		))
+	}
+
 	let sc_service::PartialComponents {
 		client,
 		backend,
@@ -141,7 +192,7 @@ pub fn new_full<
 		select_chain,
 		transaction_pool,
 		other: (block_import, grandpa_link, mut telemetry),
-	} = new_partial(&config)?;
+	} = new_partial(&config, sealing)?;
 
 	let mut net_config = sc_network::config::FullNetworkConfiguration::<
 		Block,
@@ -208,15 +259,29 @@ pub fn new_full<
 	let force_authoring = config.force_authoring;
 	let backoff_authoring_blocks: Option<()> = None;
 	let name = config.network.node_name.clone();
-	let enable_grandpa = !config.disable_grandpa;
+	// Sealed blocks are finalized by the sealing task itself.
+	let enable_grandpa = !config.disable_grandpa && sealing.is_none();
 	let prometheus_registry = config.prometheus_registry().cloned();
 
+	// Commands of the `engine_createBlock` and `engine_finalizeBlock` RPCs.
+	let (command_sink, commands_stream) = match sealing {
+		Some(_) => {
+			let (command_sink, commands_stream) = mpsc::channel(1024);
+			(Some(command_sink), Some(commands_stream))
+		},
+		None => (None, None),
+	};
+
 	let rpc_extensions_builder = {
 		let client = client.clone();
 		let pool = transaction_pool.clone();
 
 		Box::new(move |_| {
-			let deps = crate::rpc::FullDeps { client: client.clone(), pool: pool.clone() };
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				command_sink: command_sink.clone(),
+			};
 			crate::rpc::create_full(deps).map_err(Into::into)
 		})
 	};
@@ -236,7 +301,78 @@ pub fn new_full<
 		telemetry: telemetry.as_mut(),
 	})?;
 
-	if role.is_authority() {
+	if let (Some(sealing), Some(commands_stream)) = (sealing, commands_stream) {
+		let proposer_factory = sc_basic_authorship::ProposerFactory::new(
+			task_manager.spawn_handle(),
+			client.clone(),
+			transaction_pool.clone(),
+			prometheus_registry.as_ref(),
+			telemetry.as_ref().map(|x| x.handle()),
+		);
+
+		let commands_stream = match sealing {
+			Sealing::Manual => commands_stream.boxed(),
+			Sealing::Instant => {
+				let imports = transaction_pool.import_notification_stream().map(|_| {
+					EngineCommand::SealNewBlock {
+						create_empty: false,
+						finalize: true,
+						parent_hash: None,
+						sender: None,
+					}
+				});
+				futures::stream::select(commands_stream, imports).boxed()
+			},
+			Sealing::Interval(millis) => {
+				let ticks = futures::stream::unfold((), move |()| async move {
+					futures_timer::Delay::new(Duration::from_millis(millis)).await;
+					let command = EngineCommand::SealNewBlock {
+						create_empty: true,
+						finalize: true,
+						parent_hash: None,
+						sender: None,
+					};
+					Some((command, ()))
+				});
+				futures::stream::select(commands_stream, ticks).boxed()
+			},
+		};
+
+		// Sealed blocks use the current time, but at least one slot after the previous block, so
+		// that the Aura slot of the runtime increases with each block. Blocks only run ahead of
+		// the current time while they are sealed faster than once per slot, hence the previous
+		// block is initially the best block, which may be ahead of the time of a restart.
+		let slot_duration = sc_consensus_aura::slot_duration(&*client)?.as_millis();
+		let last_timestamp = Arc::new(AtomicU64::new(best_timestamp(&client)?));
+		let create_inherent_data_providers = move |_, ()| {
+			let now = sp_timestamp::Timestamp::current().as_millis();
+			let next = |last: u64| last.saturating_add(slot_duration).max(now);
+			let last = last_timestamp
+				.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| Some(next(last)))
+				.expect("closure always returns `Some`; qed");
+			let timestamp = next(last);
+			async move { Ok(sp_timestamp::InherentDataProvider::new(timestamp.into())) }
+		};
+
+		let manual_seal = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+			block_import,
+			env: proposer_factory,
+			client: client.clone(),
+			pool: transaction_pool.clone(),
+			commands_stream,
+			select_chain,
+			consensus_data_provider: Some(Box::new(AuraConsensusDataProvider::new(client))),
+			create_inherent_data_providers,
+		});
+
+		// the sealing task is considered essential, i.e. if it
+		// fails we take down the service with it.
+		task_manager.spawn_essential_handle().spawn_blocking(
+			"manual-seal",
+			Some("block-authoring"),
+			manual_seal,
+		);
+	} else if role.is_authority() {
 		let proposer_factory = sc_basic_authorship::ProposerFactory::new(
 			task_manager.spawn_handle(),
 			client.clone(),
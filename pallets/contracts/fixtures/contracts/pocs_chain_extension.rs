@@ -0,0 +1,44 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Call a PoCS chain extension with the remaining input.
+//!
+//! Returns the code returned by the chain extension followed by its output.
+#![no_std]
+#![no_main]
+
+use common::input;
+use uapi::{HostFn, HostFnImpl as api};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	input!(buffer, 128, func_id: u32, params: [u8],);
+
+	let mut output_buffer = [0u8; 132];
+	let (ret_code, output) = output_buffer.split_at_mut(4);
+	let output = &mut &mut output[..];
+
+	let ret = api::call_chain_extension(func_id, params, Some(output));
+	let len = 4 + output.len();
+	ret_code.copy_from_slice(&ret.to_le_bytes());
+
+	api::return_value(uapi::ReturnFlags::empty(), &output_buffer[..len]);
+}
@@ -0,0 +1,74 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Instantiate child contracts and manage their stake through the PoCS chain extension.
+//!
+//! The input starts with a selector followed by two 32 byte arguments:
+//! - `0`: Instantiates the code hash with the salt and returns the child's address.
+//! - `1`: Delegates the child to the validator and returns the code of the chain extension.
+//! - `2`: Hands the child over to the new owner and returns the code of the chain extension.
+//! - `3`: Validates the factory itself, ignoring the arguments, and returns the code of the
+//!   chain extension.
+#![no_std]
+#![no_main]
+
+use common::input;
+use uapi::{pocs, HostFn, HostFnImpl as api};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	input!(selector: u8, target: [u8; 32], param: [u8; 32],);
+
+	match selector {
+		0 => {
+			let mut address = [0u8; 32];
+			api::instantiate_v2(
+				target,
+				0u64, // How much ref_time weight to devote for the execution. 0 = all.
+				0u64, // How much proof_size weight to devote for the execution. 0 = all.
+				None, // No deposit limit.
+				&10_000u64.to_le_bytes(), // Value to transfer.
+				&[],
+				Some(&mut &mut address[..]),
+				None,
+				param,
+			)
+			.unwrap();
+			api::return_value(uapi::ReturnFlags::empty(), &address);
+		},
+		1 | 2 => {
+			let (target, param) = (target.try_into().unwrap(), param.try_into().unwrap());
+			let result = if selector == 1 {
+				pocs::delegate(target, param)
+			} else {
+				pocs::update_owner(target, param)
+			};
+			let code = result.map_or_else(u32::from, |()| 0);
+			api::return_value(uapi::ReturnFlags::empty(), &code.to_le_bytes());
+		},
+		3 => {
+			let code = pocs::validate().map_or_else(u32::from, |()| 0);
+			api::return_value(uapi::ReturnFlags::empty(), &code.to_le_bytes());
+		},
+		_ => panic!("unknown selector"),
+	}
+}
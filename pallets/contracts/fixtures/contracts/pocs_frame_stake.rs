@@ -0,0 +1,45 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read the gas consumed by the current frame and the stake score it would earn.
+//!
+//! Returns the gas as `u64` followed by the stake score as `u128`, both little endian, or
+//! reverts with the code of the host function.
+#![no_std]
+#![no_main]
+
+extern crate common;
+
+use uapi::{HostFn, HostFnImpl as api};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	match api::frame_stake() {
+		Ok(frame_stake) => {
+			let mut output = [0u8; 24];
+			output[..8].copy_from_slice(&frame_stake.gas_consumed.to_le_bytes());
+			output[8..].copy_from_slice(&frame_stake.stake_score.to_le_bytes());
+			api::return_value(uapi::ReturnFlags::empty(), &output);
+		},
+		Err(error) =>
+			api::return_value(uapi::ReturnFlags::REVERT, &u32::from(error).to_le_bytes()),
+	}
+}
@@ -0,0 +1,59 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read and delegate the stake of contracts through the unstable PoCS host functions.
+//!
+//! The input starts with a selector followed by two 32 byte arguments:
+//! - `0`: Returns the encoded `StakeInfo` followed by the encoded `DelegateInfo` of the
+//!   contract, or reverts with the code of the host function.
+//! - `1`: Delegates the contract to the validator and returns the code of the host function.
+#![no_std]
+#![no_main]
+
+use common::input;
+use uapi::{HostFn, HostFnImpl as api};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	input!(selector: u8, contract: [u8; 32], delegate_to: [u8; 32],);
+
+	match selector {
+		0 => {
+			let mut output_buffer = [0u8; 128];
+			let output = &mut &mut output_buffer[..];
+			if let Err(error) = api::stake_info(contract, output) {
+				api::return_value(uapi::ReturnFlags::REVERT, &u32::from(error).to_le_bytes());
+			}
+			let len = output.len();
+			let output = &mut &mut output_buffer[len..];
+			if let Err(error) = api::delegate_info(contract, output) {
+				api::return_value(uapi::ReturnFlags::REVERT, &u32::from(error).to_le_bytes());
+			}
+			let len = len + output.len();
+			api::return_value(uapi::ReturnFlags::empty(), &output_buffer[..len]);
+		},
+		1 => {
+			let code = api::delegate(contract, delegate_to).map_or_else(u32::from, |()| 0);
+			api::return_value(uapi::ReturnFlags::empty(), &code.to_le_bytes());
+		},
+		_ => panic!("unknown selector"),
+	}
+}
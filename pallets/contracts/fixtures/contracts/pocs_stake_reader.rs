@@ -0,0 +1,51 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Read the stake of a contract through the PoCS chain extension.
+//!
+//! Returns the encoded `StakeInfo` followed by the encoded `DelegateInfo` of the contract, or
+//! reverts with the code returned by the chain extension.
+#![no_std]
+#![no_main]
+
+use common::input;
+use uapi::{
+	pocs::{self, func_id, FETCH_STAKE_INFO},
+	HostFn, HostFnImpl as api,
+};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	input!(contract: [u8; 32],);
+
+	let mut output_buffer = [0u8; 128];
+	let mut len = 0;
+	for func_id in [func_id::STAKE_INFO, func_id::DELEGATE_INFO] {
+		let output = &mut &mut output_buffer[len..];
+		if let Err(error) = pocs::call(FETCH_STAKE_INFO, func_id, contract, Some(output)) {
+			api::return_value(uapi::ReturnFlags::REVERT, &u32::from(error).to_le_bytes());
+		}
+		len += output.len();
+	}
+
+	api::return_value(uapi::ReturnFlags::empty(), &output_buffer[..len]);
+}
@@ -29,7 +29,8 @@ use self::{
 use crate::{
 	exec::{Key, SeedOf},
 	migration::{
-		codegen::LATEST_MIGRATION_VERSION, v09, v10, v11, v12, v13, v14, v15, v16, MigrationStep,
+		codegen::LATEST_MIGRATION_VERSION, v09, v10, v11, v12, v13, v14, v15, v16, v17,
+		v18, MigrationStep,
 	},
 	storage::WriteOutcome,
 	wasm::BenchEnv,
@@ -219,6 +220,18 @@ fn caller_funding<T: Config>() -> BalanceOf<T> {
 	BalanceOf::<T>::max_value() / 10_000u32.into()
 }
 
+/// A full `Owners` list of the v17 migration which names none of the benchmarked contracts.
+struct V17Owners<T>(PhantomData<T>);
+
+impl<T: Config> Get<v17::OwnerList<T>> for V17Owners<T> {
+	fn get() -> v17::OwnerList<T> {
+		let owners = (0..v17::MAX_OWNERS)
+			.map(|i| (account("listed", i, 0), account("owner", i, 0)))
+			.collect::<Vec<_>>();
+		owners.try_into().expect("list holds `MAX_OWNERS` entries; qed")
+	}
+}
+
 #[benchmarks(
 	where
 		<BalanceOf<T> as codec::HasCompact>::Type: Clone + Eq + PartialEq + core::fmt::Debug + scale_info::TypeInfo + codec::Encode,
@@ -384,6 +397,44 @@ mod benchmarks {
 		Ok(())
 	}
 
+	// This benchmarks the v17 migration step (Backfill PoCS stake of unstaked contracts).
+	// The contract isn't listed in `Owners`, so the whole list is scanned before falling back to
+	// the code owner.
+	#[benchmark(pov_mode = Measured)]
+	fn v17_migration_step() -> Result<(), BenchmarkError> {
+		let contract =
+			<Contract<T>>::with_caller(whitelisted_caller(), WasmModule::dummy(), vec![])?;
+		let mut m = v17::Migration::<T, V17Owners<T>>::default();
+
+		#[block]
+		{
+			m.step(&mut WeightMeter::new());
+		}
+		let delegate_info = v17::DelegateInfoMap::<T>::get(&contract.account_id).unwrap();
+		assert_eq!(delegate_info.owner, contract.caller);
+		Ok(())
+	}
+
+	// This benchmarks the v18 migration step (Combine PoCS delegate and stake information).
+	#[benchmark(pov_mode = Measured)]
+	fn v18_migration_step() -> Result<(), BenchmarkError> {
+		let contract =
+			<Contract<T>>::with_caller(whitelisted_caller(), WasmModule::dummy(), vec![])?;
+		v18::store_old_pocs_info::<T>(contract.account_id.clone(), contract.caller.clone());
+		// Stake without delegate information additionally looks up the code owner.
+		v17::DelegateInfoMap::<T>::remove(&contract.account_id);
+		let mut m = v18::Migration::<T>::default();
+
+		#[block]
+		{
+			m.step(&mut WeightMeter::new());
+		}
+		let pocs_info = v18::PocsInfoMap::<T>::get(&contract.account_id).unwrap();
+		assert_eq!(pocs_info.delegate_info.owner, contract.caller);
+		assert!(v17::StakeInfoMap::<T>::get(&contract.account_id).is_none());
+		Ok(())
+	}
+
 	// This benchmarks the weight of executing Migration::migrate to execute a noop migration.
 	#[benchmark(pov_mode = Measured)]
 	fn migration_noop() {
@@ -1999,6 +2050,86 @@ mod benchmarks {
 		assert_eq!(result.unwrap(), 1);
 	}
 
+	// The weight of `T::ContractStake` is charged on top of this benchmark.
+	#[benchmark(pov_mode = Measured)]
+	fn seal_stake_info() {
+		let Contract { account_id, .. } =
+			Contract::<T>::with_index(1, WasmModule::dummy(), vec![]).unwrap();
+		let len = 256u32;
+		build_runtime!(runtime, memory: [len.to_le_bytes(), vec![0u8; len as _], account_id.encode(), ]);
+
+		let result;
+		#[block]
+		{
+			result = BenchEnv::seal0_stake_info(&mut runtime, &mut memory, 4 + len, 4, 0);
+		}
+
+		// Without stake the contract traps unless the error is mapped to a code.
+		let expected = T::ContractStake::stake_info(&account_id)
+			.map(|_| 0)
+			.or_else(|err| T::ContractStake::return_code(&err).ok_or(err));
+		assert_eq!(result.ok(), expected.ok());
+	}
+
+	// The weight of `T::ContractStake` is charged on top of this benchmark.
+	#[benchmark(pov_mode = Measured)]
+	fn seal_delegate_info() {
+		let Contract { account_id, .. } =
+			Contract::<T>::with_index(1, WasmModule::dummy(), vec![]).unwrap();
+		let len = 256u32;
+		build_runtime!(runtime, memory: [len.to_le_bytes(), vec![0u8; len as _], account_id.encode(), ]);
+
+		let result;
+		#[block]
+		{
+			result = BenchEnv::seal0_delegate_info(&mut runtime, &mut memory, 4 + len, 4, 0);
+		}
+
+		// Without stake the contract traps unless the error is mapped to a code.
+		let expected = T::ContractStake::delegate_info(&account_id)
+			.map(|_| 0)
+			.or_else(|err| T::ContractStake::return_code(&err).ok_or(err));
+		assert_eq!(result.ok(), expected.ok());
+	}
+
+	// The weight of `T::ContractStake` is charged on top of this benchmark.
+	#[benchmark(pov_mode = Measured)]
+	fn seal_delegate() {
+		let Contract { account_id, .. } =
+			Contract::<T>::with_index(1, WasmModule::dummy(), vec![]).unwrap();
+		let delegate_to = account::<T::AccountId>("validator", 0, 0);
+		let delegate_to_ptr = account_id.encode().len() as u32;
+		build_runtime!(runtime, memory: [account_id.encode(), delegate_to.encode(), ]);
+
+		let result;
+		#[block]
+		{
+			result = BenchEnv::seal0_delegate(&mut runtime, &mut memory, 0, delegate_to_ptr);
+		}
+
+		// The executing contract doesn't own the stake of `account_id`.
+		assert_ne!(result.ok(), Some(0));
+	}
+
+	// The weight of `T::ContractStake` is charged on top of this benchmark.
+	#[benchmark(pov_mode = Measured)]
+	fn seal_frame_stake() {
+		let len = <(u64, u128) as MaxEncodedLen>::max_encoded_len() as u32;
+		build_runtime!(runtime, memory: [len.to_le_bytes(), vec![0u8; len as _], ]);
+
+		let result;
+		#[block]
+		{
+			result = BenchEnv::seal0_frame_stake(&mut runtime, &mut memory, 4, 0);
+		}
+
+		// Without stake the contract traps unless the error is mapped to a code.
+		let expected = T::ContractStake::frame_stake_score(runtime.ext().address(), 0)
+			.map(|_| 0)
+			.or_else(|err| T::ContractStake::return_code(&err).ok_or(err));
+		assert_eq!(result.ok(), expected.ok());
+	}
+
 	// We load `i64` values from random linear memory locations and store the loaded
 	// values back into yet another random linear memory location.
 	// The random addresses are uniformly distributed across the entire span of the linear memory.
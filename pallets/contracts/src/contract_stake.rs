@@ -0,0 +1,118 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stake of contracts exposed to contracts through host functions.
+//!
+//! The `stake_info`, `delegate_info`, `delegate` and `frame_stake` host functions forward to
+//! [`Config::ContractStake`]. This allows protocols such as Proof of Contract Stake (PoCS) to
+//! be queried and driven by contracts without being part of this pallet. The `()`
+//! implementation provides no stake, hence the host functions trap.
+
+use crate::Config;
+use alloc::vec::Vec;
+use frame_support::weights::Weight;
+use sp_runtime::DispatchError;
+
+/// Provides the stake of contracts to the host functions.
+pub trait ContractStake<T: Config> {
+	/// Returns the SCALE encoded stake information of `contract`.
+	fn stake_info(contract: &T::AccountId) -> Result<Vec<u8>, DispatchError>;
+
+	/// Returns the SCALE encoded delegate information of `contract`.
+	fn delegate_info(contract: &T::AccountId) -> Result<Vec<u8>, DispatchError>;
+
+	/// Delegates the stake of `contract` to `delegate_to` on behalf of `owner`.
+	///
+	/// `owner` is the contract calling the host function.
+	fn delegate(
+		owner: &T::AccountId,
+		contract: &T::AccountId,
+		delegate_to: &T::AccountId,
+	) -> Result<(), DispatchError>;
+
+	/// Returns the stake score a frame of `contract` consuming `gas` would earn.
+	///
+	/// `gas` is the `ref_time` consumed by the frame so far, including nested frames.
+	fn frame_stake_score(contract: &T::AccountId, gas: u64) -> Result<u128, DispatchError>;
+
+	/// Maps an error returned by this provider to the code returned to the contract.
+	///
+	/// Errors mapped to `None` trap the contract.
+	fn return_code(error: &DispatchError) -> Option<u32>;
+
+	/// The weight of [`Self::stake_info`].
+	fn stake_info_weight() -> Weight;
+
+	/// The weight of [`Self::delegate_info`].
+	fn delegate_info_weight() -> Weight;
+
+	/// The weight of [`Self::delegate`].
+	fn delegate_weight() -> Weight;
+
+	/// The weight of [`Self::delegate`] if the delegation was rejected.
+	///
+	/// The difference to [`Self::delegate_weight`] is refunded.
+	fn delegate_rejected_weight() -> Weight;
+
+	/// The weight of [`Self::frame_stake_score`].
+	fn frame_stake_score_weight() -> Weight;
+}
+
+impl<T: Config> ContractStake<T> for () {
+	fn stake_info(_contract: &T::AccountId) -> Result<Vec<u8>, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn delegate_info(_contract: &T::AccountId) -> Result<Vec<u8>, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn delegate(
+		_owner: &T::AccountId,
+		_contract: &T::AccountId,
+		_delegate_to: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn frame_stake_score(_contract: &T::AccountId, _gas: u64) -> Result<u128, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn return_code(_error: &DispatchError) -> Option<u32> {
+		None
+	}
+
+	fn stake_info_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn delegate_info_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn delegate_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn delegate_rejected_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn frame_stake_score_weight() -> Weight {
+		Weight::zero()
+	}
+}
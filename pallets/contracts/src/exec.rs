@@ -17,6 +17,7 @@
 
 use crate::{
 	debug::{CallInterceptor, CallSpan, Tracing},
+	execution_hook::{ExecutedFrame, FrameHookToken, OnContractExecution},
 	gas::GasMeter,
 	primitives::{ExecReturnValue, StorageDeposit},
 	storage::{self, meter::Diff, WriteOutcome},
@@ -52,8 +53,6 @@ use sp_runtime::{
 	traits::{Convert, Dispatchable, Zero},
 	DispatchError,
 };
-use crate::stake::StakeRequest;
-
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
@@ -955,8 +954,9 @@ where
 	fn run(&mut self, executable: E, input_data: Vec<u8>) -> Result<ExecReturnValue, ExecError> {
 		let frame = self.top_frame();
 		let entry_point = frame.entry_point;
+		let code_hash = *executable.code_hash();
 		let delegated_code_hash =
-			if frame.delegate_caller.is_some() { Some(*executable.code_hash()) } else { None };
+			if frame.delegate_caller.is_some() { Some(code_hash) } else { None };
 
 		self.transient_storage.start_transaction();
 
@@ -1011,8 +1011,7 @@ where
 			let frame = self.top_frame();
 			let account_id = &frame.account_id.clone();
 
-			let gas: u64 = frame.nested_gas.gas_consumed().ref_time();
-			let caller = self.caller().account_id()?.clone();
+			let gas_consumed = frame.nested_gas.gas_consumed();
 
 			match (entry_point, delegated_code_hash) {
 				(ExportedFunction::Constructor, _) => {
@@ -1056,9 +1055,24 @@ where
 					});
 				},
 			}
-			
-			// Initiate Stake (PoCS)
-			StakeRequest::<T>::stake(&caller, &account_id, &gas)?;
+
+			// A contract that terminated itself no longer exists.
+			if !matches!(self.top_frame().contract_info, CachedContract::Terminated) {
+				let executed_frame = ExecutedFrame {
+					origin: self.origin.clone(),
+					caller: self.caller(),
+					contract: account_id.clone(),
+					code_hash,
+					entry_point,
+					delegated: delegated_code_hash.is_some(),
+					gas_consumed,
+				};
+				let weight = T::OnContractExecution::frame_weight(&executed_frame);
+				if !weight.is_zero() {
+					top_frame_mut!(self).nested_gas.charge(FrameHookToken(weight))?;
+				}
+				T::OnContractExecution::on_frame_executed(&executed_frame)?;
+			}
 			Ok(output)
 		};
 
@@ -1092,7 +1106,27 @@ where
 			self.transient_storage.rollback_transaction();
 		}
 
+		// Report the outcome outside of the frame's storage transaction so that trapped and
+		// reverted calls are accounted for. Calls failing for reasons the contract can't control,
+		// e.g. running out of gas, aren't reported at all.
+		let outcome = match &output {
+			Ok(_) => Some(success),
+			Err(ExecError { error, .. }) if *error == Error::<T>::ContractTrapped.into() =>
+				Some(false),
+			Err(_) => None,
+		};
+		if let Some(success) = outcome {
+			if entry_point == ExportedFunction::Call && delegated_code_hash.is_none() {
+				T::OnContractExecution::on_call_outcome(&self.top_frame().account_id, success);
+			}
+		}
+
+		// The execution ends with the first frame, which isn't part of `frames`.
+		let is_first_frame = self.frames.is_empty();
 		self.pop_frame(success);
+		if is_first_frame {
+			T::OnContractExecution::on_execution_finished();
+		}
 		output
 	}
 
@@ -1390,8 +1424,7 @@ where
 			contract: frame.account_id.clone(),
 			beneficiary: beneficiary.clone(),
 		});
-		// Delete Stake (PoCS)
-		StakeRequest::<T>::delete(&frame.account_id);
+		T::OnContractExecution::on_contract_terminated(&frame.account_id);
 		Ok(())
 	}
 
@@ -1616,6 +1649,9 @@ where
 			new_code_hash: hash,
 			old_code_hash: prev_hash,
 		});
+		if prev_hash != hash {
+			T::OnContractExecution::on_code_changed(&frame.account_id, &prev_hash, &hash);
+		}
 		Ok(())
 	}
 
@@ -0,0 +1,140 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hooks notifying other pallets about the execution of contracts.
+//!
+//! The contracts pallet calls into [`Config::OnContractExecution`] whenever a stack frame
+//! finished executing, a contract was terminated, the code of a contract was replaced or an
+//! execution requested by an origin came to an end. This allows protocols such as Proof of
+//! Contract Stake (PoCS) to track contract usage without being part of this pallet. The `()`
+//! implementation does nothing.
+
+pub use crate::exec::ExportedFunction;
+use crate::{gas::Token, Config, Origin};
+use frame_support::weights::Weight;
+use sp_runtime::DispatchError;
+
+/// A stack frame that finished executing without reverting.
+pub struct ExecutedFrame<T: Config> {
+	/// The origin of the transaction the frame is part of.
+	pub origin: Origin<T>,
+	/// The caller of the frame, i.e. the origin or the calling contract.
+	pub caller: Origin<T>,
+	/// The contract in whose context the frame was executed.
+	pub contract: T::AccountId,
+	/// The code executed by the frame.
+	///
+	/// For delegate calls this is the delegated code and not the code of `contract`.
+	pub code_hash: T::Hash,
+	/// Whether the frame executed the constructor or a regular call.
+	pub entry_point: ExportedFunction,
+	/// Whether the frame was entered through a delegate call.
+	pub delegated: bool,
+	/// The gas consumed by the frame, including the gas of all nested frames.
+	pub gas_consumed: Weight,
+}
+
+/// Defines callbacks invoked while contracts are executed.
+///
+/// Implementations must be cheap: they run for every frame of every contract execution. Only
+/// the weight returned by [`Self::frame_weight`] is charged to the caller, all other callbacks
+/// are free.
+pub trait OnContractExecution<T: Config> {
+	/// Returns the worst case weight of [`Self::on_frame_executed`] for `frame`.
+	///
+	/// It is charged to the gas meter of the frame before the callback is invoked. Running out
+	/// of gas fails the frame.
+	fn frame_weight(frame: &ExecutedFrame<T>) -> Weight;
+
+	/// Called after a frame executed without reverting.
+	///
+	/// The callback runs within the frame's storage transaction, hence its changes are rolled
+	/// back together with the frame's. Returning an error fails the frame. It isn't called for
+	/// frames whose contract terminated itself.
+	fn on_frame_executed(frame: &ExecutedFrame<T>) -> Result<(), DispatchError>;
+
+	/// Called with the outcome of a frame calling `contract`.
+	///
+	/// The callback runs outside of the frame's storage transaction, hence trapped and reverted
+	/// calls are reported as well. Calls failing for any other reason, e.g. running out of gas
+	/// or a failed transfer, aren't reported. It isn't called for constructors and delegate
+	/// calls.
+	fn on_call_outcome(contract: &T::AccountId, success: bool);
+
+	/// Called after `contract` was terminated.
+	fn on_contract_terminated(contract: &T::AccountId);
+
+	/// Called after the code of `contract` was replaced via `seal_set_code_hash` or
+	/// `set_code`.
+	///
+	/// It isn't called if the new code equals the old one.
+	fn on_code_changed(contract: &T::AccountId, old_code_hash: &T::Hash, new_code_hash: &T::Hash);
+
+	/// Called after the code with `code_hash` was removed.
+	fn on_code_removed(code_hash: &T::Hash);
+
+	/// Called once the outermost frame of an execution, i.e., the call or instantiation
+	/// requested by the origin, was popped.
+	///
+	/// The callback runs outside of any frame's storage transaction. It is called whether the
+	/// execution succeeded or not.
+	fn on_execution_finished();
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<T: Config> OnContractExecution<T> for Tuple {
+	fn frame_weight(frame: &ExecutedFrame<T>) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::frame_weight(frame)); )* );
+		weight
+	}
+
+	fn on_frame_executed(frame: &ExecutedFrame<T>) -> Result<(), DispatchError> {
+		for_tuples!( #( Tuple::on_frame_executed(frame)?; )* );
+		Ok(())
+	}
+
+	fn on_call_outcome(contract: &T::AccountId, success: bool) {
+		for_tuples!( #( Tuple::on_call_outcome(contract, success); )* );
+	}
+
+	fn on_contract_terminated(contract: &T::AccountId) {
+		for_tuples!( #( Tuple::on_contract_terminated(contract); )* );
+	}
+
+	fn on_code_changed(contract: &T::AccountId, old_code_hash: &T::Hash, new_code_hash: &T::Hash) {
+		for_tuples!( #( Tuple::on_code_changed(contract, old_code_hash, new_code_hash); )* );
+	}
+
+	fn on_code_removed(code_hash: &T::Hash) {
+		for_tuples!( #( Tuple::on_code_removed(code_hash); )* );
+	}
+
+	fn on_execution_finished() {
+		for_tuples!( #( Tuple::on_execution_finished(); )* );
+	}
+}
+
+/// Charges the weight of [`OnContractExecution::on_frame_executed`].
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+#[derive(Clone, Copy)]
+pub(crate) struct FrameHookToken(pub Weight);
+
+impl<T: Config> Token<T> for FrameHookToken {
+	fn weight(&self) -> Weight {
+		self.0
+	}
+}
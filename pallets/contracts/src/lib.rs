@@ -99,10 +99,11 @@ mod schedule;
 mod storage;
 mod transient_storage;
 mod wasm;
-pub mod stake;
 
 pub mod chain_extension;
+pub mod contract_stake;
 pub mod debug;
+pub mod execution_hook;
 pub mod migration;
 pub mod test_utils;
 pub mod weights;
@@ -144,8 +145,10 @@ use sp_runtime::{
 
 pub use crate::{
 	address::{AddressGenerator, DefaultAddressGenerator},
+	contract_stake::ContractStake,
 	debug::Tracing,
 	exec::Frame,
+	execution_hook::OnContractExecution,
 	migration::{MigrateSequence, Migration, NoopMigration},
 	pallet::*,
 	schedule::{InstructionWeights, Limits, Schedule},
@@ -155,8 +158,6 @@ pub use weights::WeightInfo;
 
 #[cfg(doc)]
 pub use crate::wasm::api_doc;
-use stake::{DelegateRequest, ValidateRequest};
-
 
 type CodeHash<T> = <T as frame_system::Config>::Hash;
 type TrieId = BoundedVec<u8, ConstU32<128>>;
@@ -249,7 +250,7 @@ pub mod pallet {
 	use sp_runtime::Perbill;
 
 	/// The in-code storage version.
-	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(16);
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(18);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -487,6 +488,19 @@ pub mod pallet {
 			<Self as frame_system::Config>::RuntimeCall,
 			BlockNumberFor<Self>,
 		>;
+
+		/// Callbacks invoked while contracts are executed, e.g. to track their usage.
+		///
+		/// Use `()` if no other pallet needs to be notified.
+		#[pallet::no_default_bounds]
+		type OnContractExecution: OnContractExecution<Self>;
+
+		/// Provides the stake of contracts to the unstable `stake_info`, `delegate_info`,
+		/// `delegate` and `frame_stake` host functions.
+		///
+		/// Use `()` if contracts have no stake.
+		#[pallet::no_default_bounds]
+		type ContractStake: ContractStake<Self>;
 	}
 
 	/// Container for different types that implement [`DefaultConfig`]` of this pallet.
@@ -576,6 +590,8 @@ pub mod pallet {
 			type Environment = ();
 			type ApiVersion = ();
 			type Xcm = ();
+			type OnContractExecution = ();
+			type ContractStake = ();
 		}
 	}
 
@@ -914,6 +930,9 @@ pub mod pallet {
 					new_code_hash: code_hash,
 					old_code_hash: contract.code_hash,
 				});
+				if contract.code_hash != code_hash {
+					T::OnContractExecution::on_code_changed(&dest, &contract.code_hash, &code_hash);
+				}
 				contract.code_hash = code_hash;
 				Ok(())
 			})
@@ -1127,39 +1146,6 @@ pub mod pallet {
 				},
 			}
 		}
-
-		#[pallet::call_index(10)]
-		#[pallet::weight(0)]
-		pub fn delegate(
-			origin: OriginFor<T>,
-			contract_addr: T::AccountId,
-			delegate_to: T::AccountId,
-		)-> DispatchResult {
-			let origin = ensure_signed(origin.clone())?;
-			<DelegateRequest<T>>::delegate(&origin,&contract_addr,&delegate_to)?;
-			Ok(())
-		}
-
-		#[pallet::call_index(11)]
-		#[pallet::weight(0)]
-		pub fn update_owner(
-			origin: OriginFor<T>,
-			contract_addr: T::AccountId,
-			new_owner: T::AccountId,
-		)-> DispatchResult {
-			let origin = ensure_signed(origin.clone())?;
-			<DelegateRequest<T>>::update_stake_owner(&origin,&contract_addr,&new_owner)?;
-			Ok(())
-		}
-
-		#[pallet::call_index(12)]
-		#[pallet::weight(0)]
-		pub fn validate(origin:OriginFor<T>) -> DispatchResult {
-			let validator = ensure_signed(origin.clone())?;
-			<ValidateRequest<T>>::validate(&validator)?;
-			Ok(())
-		}
-
 	}
 
 	#[pallet::event]
@@ -1247,69 +1233,14 @@ pub mod pallet {
 			to: T::AccountId,
 			amount: BalanceOf<T>,
 		},
-
-		/// Stake Score is updated for a contract (PoCS)
-		Staked {
-			/// The contract address for which stake information is updated
-			contract: T::AccountId,
-			/// The contract's associated stake score
-			stake_score: u128,
-		},
-
-		/// Announce a contract meets minimum reputation for staking 
-		/// Now it can call [`Pallet::delegate`], update its delegate and stake the contract
-		ReadyToStake {
-			/// The contract address which is ready for staking / delegation 
-			contract: T::AccountId
-		},
-		
-		/// Delegate Information is updated for a contract via [`Pallet::delegate`] (PoCS) 
-		Delegated {
-			/// The contract address for which delegate information is updated by its owner
-			contract: T::AccountId,
-			/// The contract delegated to which account address i.e., the validator
-			delegate_to: T::AccountId,
-		},
-
-		/// Validator validation criteria information as event (PoCS)
-		ValidateInfo {
-			/// The validator's account address i.e., a contract address
-			validator: T::AccountId,
-			/// Number of delegates which the validator can utilize for validation
-			num_delegates: u32,
-			/// Provides Assurance if the validator can start validating
-			can_validate: bool
-		},
-
-		/// Stake Owner is updated for a contract via [`Pallet::update_owner`] (PoCS) 
-		StakeOwner {
-			/// The contract address for which owner information is updated 
-			contract: T::AccountId,
-			/// The new stake owner of the contract
-			new_owner: T::AccountId,
-		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
-		/// Stake information for account is not available (PoCS)
-		NoStakeExists,
 		/// Invalid schedule supplied, e.g. with zero weight of a basic operation.
 		InvalidSchedule,
 		/// Invalid combination of flags supplied to `seal_call` or `seal_delegate_call`.
 		InvalidCallFlags,
-		/// The contract or account is already delegated to the same address (PoCS)
-		AlreadyDelegated,
-		/// The contract does not meet the minimum reputation requirement (PoCS)
-		LowReputation,
-		/// Invalid Owner of a contract (PoCS)
-		InvalidContractOwner,
-		/// The contract or account is already owned by the given account address (PoCS)
-		AlreadyOwner,
-		/// The required minimum number of delegates has not been met for validation (PoCS)
-		InsufficientDelegates,
-		/// No validator was found for the given contract address (PoCS)
-		NoValidatorFound,
 		/// The executed contract exhausted its gas limit.
 		OutOfGas,
 		/// The output buffer supplied to a contract API call was too small.
@@ -1452,27 +1383,6 @@ pub mod pallet {
 	pub(crate) type ContractInfoOf<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ContractInfo<T>>;
 
-	// ./stake/mod.rs - structure
-	use crate::stake::{DelegateInfo,StakeInfo};
-
-	/// Tracks Delegate Information of a staked contract (PoCS)
-	#[pallet::storage]
-	#[pallet::getter(fn get_delegate_info)]
-	pub type DelegateInfoMap<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, DelegateInfo<T>>;
-
-	/// Tracks Stake Score Information of a contract (PoCS)
-	#[pallet::storage]
-	#[pallet::getter(fn get_stake_info)]
-	pub type StakeInfoMap<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, StakeInfo<T>>;
-
-	/// Tracks Number of delegates associated with a validator (PoCS)
-	/// 
-	/// Gets updated via [`Pallet::delegate`] extrinsic.
-	#[pallet::storage]
-	#[pallet::getter(fn get_validator_info)]
-	pub type ValidatorInfoMap<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32>;
-
-	
 	/// Evicted contracts that await child trie deletion.
 	///
 	/// Child trie deletion is a heavy operation depending on the amount of storage items
@@ -1491,6 +1401,50 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type MigrationInProgress<T: Config> =
 		StorageValue<_, migration::Cursor, OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Contracts deployed at genesis
+		///
+		/// Each entry is `(deployer, code, data, salt, value)`. The code is uploaded and the
+		/// contract instantiated by the deployer, limited to the weight of a single extrinsic.
+		pub contracts: Vec<(T::AccountId, Vec<u8>, Vec<u8>, Vec<u8>, u128)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			// Each contract may use as much weight as a single extrinsic could
+			let block_weights = <T as frame_system::Config>::BlockWeights::get();
+			let gas_limit = block_weights
+				.get(DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or(block_weights.max_block);
+			for (deployer, code, data, salt, value) in &self.contracts {
+				let value = (*value).try_into().unwrap_or_else(|_| {
+					panic!("Genesis value of the contract of {:?} overflows the balance", deployer)
+				});
+				let result = Pallet::<T>::bare_instantiate(
+					deployer.clone(),
+					value,
+					gas_limit,
+					None,
+					Code::Upload(code.clone()),
+					data.clone(),
+					salt.clone(),
+					DebugInfo::Skip,
+					CollectEvents::Skip,
+				)
+				.result;
+				match result {
+					Ok(result) if !result.result.did_revert() => {},
+					Ok(_) => panic!("Genesis contract of {:?} reverted", deployer),
+					Err(err) => panic!("Genesis contract of {:?} failed: {:?}", deployer, err),
+				}
+			}
+		}
+	}
 }
 
 /// The type of origins supported by the contracts pallet.
@@ -2004,6 +1958,12 @@ impl<T: Config> Pallet<T> {
 		ContractInfo::<T>::load_code_hash(account)
 	}
 
+	/// Returns the owner of the code specified by `code_hash`, i.e. the account that has
+	/// uploaded it.
+	pub fn code_owner(code_hash: &CodeHash<T>) -> Option<AccountIdOf<T>> {
+		CodeInfoOf::<T>::get(code_hash).map(|code_info| code_info.owner().clone())
+	}
+
 	/// Store code for benchmarks which does not validate the code.
 	#[cfg(feature = "runtime-benchmarks")]
 	fn store_code_raw(
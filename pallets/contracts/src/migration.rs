@@ -65,6 +65,8 @@ pub mod v13;
 pub mod v14;
 pub mod v15;
 pub mod v16;
+pub mod v17;
+pub mod v18;
 include!(concat!(env!("OUT_DIR"), "/migration_codegen.rs"));
 
 use crate::{weights::WeightInfo, Config, Error, MigrationInProgress, Pallet, Weight, LOG_TARGET};
@@ -0,0 +1,179 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Backfill default stake and delegate information (PoCS) for contracts that predate staking.
+//!
+//! Contracts only receive stake information on their next execution. This creates it for every
+//! contract in `ContractInfoOf` that has none. The stake owner is taken from the governance
+//! provided `Owners` list if the contract is listed there. Otherwise it falls back to the owner
+//! of the contract's code, i.e., the account that paid the code deposit, since storage deposits
+//! are held on the contract itself and no longer name a separate deposit account. Contracts whose
+//! owner can not be inferred are skipped and receive stake information on their next execution.
+//!
+//! The `Owners` list is scanned for every contract, hence it is bounded by [`MAX_OWNERS`] and the
+//! step weight is benchmarked with a full list that doesn't name the migrated contract.
+
+use crate::{
+	migration::{IsFinished, MigrationStep},
+	weights::WeightInfo,
+	BalanceOf, CodeHash, CodeInfoOf, Config, Pallet, TrieId, Weight, LOG_TARGET,
+};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{pallet_prelude::*, storage_alias, weights::WeightMeter, DefaultNoBound};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::BoundedBTreeMap;
+
+/// The reputation a backfilled contract starts with.
+pub const REPUTATION_FACTOR: u32 = 1;
+
+/// The stake score a backfilled contract starts with.
+pub const INITIAL_STAKE_SCORE: u128 = 0;
+
+/// The maximum number of entries in the governance provided `Owners` list.
+pub const MAX_OWNERS: u32 = 256;
+
+/// The governance provided list of contracts and their stake owners.
+pub type OwnerList<T> = BoundedVec<
+	(<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId),
+	ConstU32<MAX_OWNERS>,
+>;
+
+#[storage_alias]
+pub type ContractInfoOf<T: Config> =
+	StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, ContractInfo<T>>;
+
+#[derive(Encode, Decode, CloneNoBound, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct ContractInfo<T: Config> {
+	trie_id: TrieId,
+	code_hash: CodeHash<T>,
+	storage_bytes: u32,
+	storage_items: u32,
+	storage_byte_deposit: BalanceOf<T>,
+	storage_item_deposit: BalanceOf<T>,
+	storage_base_deposit: BalanceOf<T>,
+	delegate_dependencies: BoundedBTreeMap<CodeHash<T>, BalanceOf<T>, T::MaxDelegateDependencies>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct DelegateInfo<T: Config> {
+	pub owner: T::AccountId,
+	pub delegate_to: T::AccountId,
+	pub delegate_at: BlockNumberFor<T>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct StakeInfo<T: Config> {
+	pub reputation: u32,
+	pub blockheight: BlockNumberFor<T>,
+	pub stake_score: u128,
+}
+
+#[storage_alias]
+pub type DelegateInfoMap<T: Config> =
+	StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, DelegateInfo<T>>;
+
+#[storage_alias]
+pub type StakeInfoMap<T: Config> =
+	StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, StakeInfo<T>>;
+
+#[derive(Encode, Decode, MaxEncodedLen, DefaultNoBound)]
+pub struct Migration<T: Config, Owners = ()> {
+	last_account: Option<T::AccountId>,
+	_phantom: PhantomData<Owners>,
+}
+
+/// Returns the uploader of the code of `contract`, if the contract and its code exist.
+pub fn code_owner<T: Config>(contract: &T::AccountId) -> Option<T::AccountId> {
+	ContractInfoOf::<T>::get(contract)
+		.and_then(|info| CodeInfoOf::<T>::get(info.code_hash))
+		.map(|code| code.owner().clone())
+}
+
+impl<T: Config, Owners> Migration<T, Owners>
+where
+	Owners: Get<OwnerList<T>>,
+{
+	/// Infers the stake owner of a contract from the governance list or its code owner.
+	fn owner(contract: &T::AccountId, info: &ContractInfo<T>) -> Option<T::AccountId> {
+		Owners::get()
+			.into_iter()
+			.find_map(|(listed, owner)| (listed == *contract).then_some(owner))
+			.or_else(|| CodeInfoOf::<T>::get(info.code_hash).map(|code| code.owner().clone()))
+	}
+}
+
+impl<T: Config, Owners: 'static> MigrationStep for Migration<T, Owners>
+where
+	Owners: Get<OwnerList<T>>,
+{
+	const VERSION: u16 = 17;
+
+	fn max_step_weight() -> Weight {
+		T::WeightInfo::v17_migration_step()
+	}
+
+	fn step(&mut self, meter: &mut WeightMeter) -> IsFinished {
+		let mut iter = if let Some(last_account) = self.last_account.take() {
+			ContractInfoOf::<T>::iter_from(ContractInfoOf::<T>::hashed_key_for(last_account))
+		} else {
+			ContractInfoOf::<T>::iter()
+		};
+
+		if let Some((key, info)) = iter.next() {
+			let staked =
+				StakeInfoMap::<T>::contains_key(&key) || DelegateInfoMap::<T>::contains_key(&key);
+			match Self::owner(&key, &info) {
+				Some(_) if staked => {
+					log::debug!(target: LOG_TARGET, "Contract {:?} is already staked", key);
+				},
+				Some(owner) => {
+					log::debug!(target: LOG_TARGET, "Backfilling stake of contract {:?}", key);
+					let block_number = frame_system::Pallet::<T>::block_number();
+					StakeInfoMap::<T>::insert(
+						&key,
+						StakeInfo {
+							reputation: REPUTATION_FACTOR,
+							blockheight: block_number,
+							stake_score: INITIAL_STAKE_SCORE,
+						},
+					);
+					DelegateInfoMap::<T>::insert(
+						&key,
+						DelegateInfo {
+							owner: owner.clone(),
+							delegate_to: owner,
+							delegate_at: block_number,
+						},
+					);
+				},
+				None => {
+					log::debug!(target: LOG_TARGET, "No owner found for contract {:?}", key);
+				},
+			}
+			self.last_account = Some(key);
+			meter.consume(T::WeightInfo::v17_migration_step());
+			IsFinished::No
+		} else {
+			log::debug!(target: LOG_TARGET, "No more contracts to backfill");
+			meter.consume(T::WeightInfo::v17_migration_step());
+			IsFinished::Yes
+		}
+	}
+}
@@ -0,0 +1,159 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Combine the `DelegateInfoMap` and `StakeInfoMap` entries (PoCS) of each contract into a single
+//! `PocsInfoMap` entry, so that a stake update reads and writes the trie only once.
+//!
+//! A contract with stake but without delegate information is migrated with a default delegation
+//! owned by the uploader of its code. Stake of accounts which are no longer contracts can't be
+//! assigned an owner and is dropped. The try-runtime checks report how many entries are dropped.
+
+use crate::{
+	migration::{
+		v17::{self, INITIAL_STAKE_SCORE, REPUTATION_FACTOR},
+		IsFinished, MigrationStep,
+	},
+	weights::WeightInfo,
+	Config, Pallet, Weight, LOG_TARGET,
+};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{pallet_prelude::*, storage_alias, weights::WeightMeter, DefaultNoBound};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub fn store_old_pocs_info<T: Config>(account: T::AccountId, owner: T::AccountId) {
+	let block_number = frame_system::Pallet::<T>::block_number();
+	v17::DelegateInfoMap::<T>::insert(
+		&account,
+		v17::DelegateInfo { owner: owner.clone(), delegate_to: owner, delegate_at: block_number },
+	);
+	v17::StakeInfoMap::<T>::insert(
+		&account,
+		v17::StakeInfo {
+			reputation: REPUTATION_FACTOR,
+			blockheight: block_number,
+			stake_score: INITIAL_STAKE_SCORE,
+		},
+	);
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct PocsInfo<T: Config> {
+	pub delegate_info: v17::DelegateInfo<T>,
+	pub stake_info: v17::StakeInfo<T>,
+}
+
+#[storage_alias]
+pub type PocsInfoMap<T: Config> =
+	StorageMap<Pallet<T>, Twox64Concat, <T as frame_system::Config>::AccountId, PocsInfo<T>>;
+
+#[derive(Encode, Decode, MaxEncodedLen, DefaultNoBound)]
+pub struct Migration<T: Config> {
+	_phantom: PhantomData<T>,
+}
+
+impl<T: Config> MigrationStep for Migration<T> {
+	const VERSION: u16 = 18;
+
+	fn max_step_weight() -> Weight {
+		T::WeightInfo::v18_migration_step()
+	}
+
+	fn step(&mut self, meter: &mut WeightMeter) -> IsFinished {
+		// Migrated entries are removed, hence the next entry is always the first remaining one.
+		if let Some((key, delegate_info)) = v17::DelegateInfoMap::<T>::drain().next() {
+			log::debug!(target: LOG_TARGET, "Migrating stake of contract {:?}", key);
+			// Keep the delegation of a contract without stake by starting it from scratch.
+			let stake_info = v17::StakeInfoMap::<T>::take(&key).unwrap_or_else(|| v17::StakeInfo {
+				reputation: REPUTATION_FACTOR,
+				blockheight: frame_system::Pallet::<T>::block_number(),
+				stake_score: INITIAL_STAKE_SCORE,
+			});
+			PocsInfoMap::<T>::insert(&key, PocsInfo { delegate_info, stake_info });
+			meter.consume(T::WeightInfo::v18_migration_step());
+			IsFinished::No
+		} else if let Some((key, stake_info)) = v17::StakeInfoMap::<T>::drain().next() {
+			match v17::code_owner::<T>(&key) {
+				Some(owner) => {
+					log::debug!(target: LOG_TARGET, "No delegate found for contract {:?}", key);
+					let delegate_info = v17::DelegateInfo {
+						owner: owner.clone(),
+						delegate_to: owner,
+						delegate_at: frame_system::Pallet::<T>::block_number(),
+					};
+					PocsInfoMap::<T>::insert(&key, PocsInfo { delegate_info, stake_info });
+				},
+				None => {
+					log::warn!(target: LOG_TARGET, "Dropping stake of removed contract {:?}", key);
+				},
+			}
+			meter.consume(T::WeightInfo::v18_migration_step());
+			IsFinished::No
+		} else {
+			log::debug!(target: LOG_TARGET, "No more stake to migrate");
+			meter.consume(T::WeightInfo::v18_migration_step());
+			IsFinished::Yes
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade_step() -> Result<Vec<u8>, TryRuntimeError> {
+		let delegated = v17::DelegateInfoMap::<T>::iter_keys().count() as u32;
+		let (mut migrated, mut dropped) = (delegated, 0u32);
+		for key in v17::StakeInfoMap::<T>::iter_keys() {
+			if v17::DelegateInfoMap::<T>::contains_key(&key) {
+				continue
+			}
+			if v17::code_owner::<T>(&key).is_some() {
+				migrated += 1;
+			} else {
+				dropped += 1;
+			}
+		}
+		if dropped > 0 {
+			log::warn!(target: LOG_TARGET, "Dropping the stake of {} removed contracts", dropped);
+		}
+		Ok((migrated, dropped).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade_step(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let (migrated, dropped) = <(u32, u32)>::decode(&mut &state[..])
+			.map_err(|_| "Failed to decode the v18 pre-upgrade state")?;
+		log::info!(
+			target: LOG_TARGET,
+			"Migrated the stake of {} contracts, dropped {}",
+			migrated,
+			dropped
+		);
+		ensure!(
+			v17::DelegateInfoMap::<T>::iter_keys().next().is_none(),
+			"DelegateInfoMap isn't empty"
+		);
+		ensure!(v17::StakeInfoMap::<T>::iter_keys().next().is_none(), "StakeInfoMap isn't empty");
+		ensure!(
+			PocsInfoMap::<T>::iter_keys().count() as u32 == migrated,
+			"Not every contract with stake was migrated"
+		);
+		Ok(())
+	}
+}
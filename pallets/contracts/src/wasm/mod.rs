@@ -43,6 +43,7 @@ pub use crate::wasm::{
 
 use crate::{
 	exec::{ExecResult, Executable, ExportedFunction, Ext},
+	execution_hook::OnContractExecution,
 	gas::{GasMeter, Token},
 	weights::WeightInfo,
 	AccountIdOf, BadOrigin, BalanceOf, CodeHash, CodeInfoOf, CodeVec, Config, Error, Event,
@@ -191,6 +192,7 @@ impl<T: Config> WasmBlob<T> {
 
 				*existing = None;
 				<PristineCode<T>>::remove(&code_hash);
+				T::OnContractExecution::on_code_removed(&code_hash);
 				<Pallet<T>>::deposit_event(Event::CodeRemoved {
 					code_hash,
 					deposit_released,
@@ -319,6 +321,11 @@ impl<T: Config> CodeInfo<T> {
 		}
 	}
 
+	/// Returns the account that has uploaded the module.
+	pub fn owner(&self) -> &AccountIdOf<T> {
+		&self.owner
+	}
+
 	/// Returns the determinism of the module.
 	pub fn determinism(&self) -> Determinism {
 		self.determinism
@@ -18,6 +18,7 @@
 //! Environment definition of the wasm smart-contract runtime.
 
 use crate::{
+	contract_stake::ContractStake,
 	exec::{ExecError, ExecResult, Ext, Key, TopicOf},
 	gas::{ChargedAmount, Token},
 	primitives::ExecReturnValue,
@@ -253,6 +254,16 @@ pub enum RuntimeCosts {
 	LockDelegateDependency,
 	/// Weight of calling `unlock_delegate_dependency`
 	UnlockDelegateDependency,
+	/// Weight of calling `stake_info`
+	StakeInfo,
+	/// Weight of calling `delegate_info`
+	DelegateInfo,
+	/// Weight of calling `delegate`
+	Delegate,
+	/// Weight of calling `delegate` if the delegation was rejected
+	DelegateRejected,
+	/// Weight of calling `frame_stake`
+	FrameStake,
 }
 
 /// For functions that modify storage, benchmarks are performed with one item in the
@@ -374,6 +385,16 @@ impl<T: Config> Token<T> for RuntimeCosts {
 			InstantiationNonce => T::WeightInfo::seal_instantiation_nonce(),
 			LockDelegateDependency => T::WeightInfo::lock_delegate_dependency(),
 			UnlockDelegateDependency => T::WeightInfo::unlock_delegate_dependency(),
+			StakeInfo => T::WeightInfo::seal_stake_info()
+				.saturating_add(T::ContractStake::stake_info_weight()),
+			DelegateInfo => T::WeightInfo::seal_delegate_info()
+				.saturating_add(T::ContractStake::delegate_info_weight()),
+			Delegate =>
+				T::WeightInfo::seal_delegate().saturating_add(T::ContractStake::delegate_weight()),
+			DelegateRejected => T::WeightInfo::seal_delegate()
+				.saturating_add(T::ContractStake::delegate_rejected_weight()),
+			FrameStake => T::WeightInfo::seal_frame_stake()
+				.saturating_add(T::ContractStake::frame_stake_score_weight()),
 		}
 	}
 }
@@ -514,6 +535,13 @@ impl<'a, E: Ext + 'a> Runtime<'a, E> {
 		self.ext.gas_meter_mut().adjust_gas(charged, actual_costs);
 	}
 
+	/// Map an error of [`Config::ContractStake`] to the code returned to the contract.
+	///
+	/// Errors without a code trap the contract.
+	fn stake_error_code(error: DispatchError) -> Result<u32, TrapReason> {
+		<E::T as Config>::ContractStake::return_code(&error).ok_or_else(|| error.into())
+	}
+
 	/// Charge, Run and adjust gas, for executing the given dispatchable.
 	fn call_dispatchable<ErrorReturnCode: Get<ReturnErrorCode>>(
 		&mut self,
@@ -2546,4 +2574,110 @@ pub mod env {
 		ctx.ext.unlock_delegate_dependency(&code_hash)?;
 		Ok(())
 	}
+
+	/// Retrieve the SCALE encoded stake information of a contract.
+	/// See [`pallet_contracts_uapi::HostFn::stake_info`].
+	#[unstable]
+	fn stake_info(
+		ctx: _,
+		memory: _,
+		contract_ptr: u32,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<u32, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::StakeInfo)?;
+		let contract: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, contract_ptr)?;
+		match <E::T as Config>::ContractStake::stake_info(&contract) {
+			Ok(stake_info) => {
+				ctx.write_sandbox_output(
+					memory,
+					out_ptr,
+					out_len_ptr,
+					&stake_info,
+					false,
+					already_charged,
+				)?;
+				Ok(0)
+			},
+			Err(err) => Runtime::<E>::stake_error_code(err),
+		}
+	}
+
+	/// Retrieve the SCALE encoded delegate information of a contract.
+	/// See [`pallet_contracts_uapi::HostFn::delegate_info`].
+	#[unstable]
+	fn delegate_info(
+		ctx: _,
+		memory: _,
+		contract_ptr: u32,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<u32, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::DelegateInfo)?;
+		let contract: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, contract_ptr)?;
+		match <E::T as Config>::ContractStake::delegate_info(&contract) {
+			Ok(delegate_info) => {
+				ctx.write_sandbox_output(
+					memory,
+					out_ptr,
+					out_len_ptr,
+					&delegate_info,
+					false,
+					already_charged,
+				)?;
+				Ok(0)
+			},
+			Err(err) => Runtime::<E>::stake_error_code(err),
+		}
+	}
+
+	/// Delegate the stake of a contract owned by the executing contract.
+	/// See [`pallet_contracts_uapi::HostFn::delegate`].
+	#[unstable]
+	#[mutating]
+	fn delegate(
+		ctx: _,
+		memory: _,
+		contract_ptr: u32,
+		delegate_to_ptr: u32,
+	) -> Result<u32, TrapReason> {
+		let charged = ctx.charge_gas(RuntimeCosts::Delegate)?;
+		let contract: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, contract_ptr)?;
+		let delegate_to: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(memory, delegate_to_ptr)?;
+		let owner = ctx.ext.address().clone();
+		match <E::T as Config>::ContractStake::delegate(&owner, &contract, &delegate_to) {
+			Ok(()) => Ok(0),
+			Err(err) => {
+				ctx.adjust_gas(charged, RuntimeCosts::DelegateRejected);
+				Runtime::<E>::stake_error_code(err)
+			},
+		}
+	}
+
+	/// Retrieve the gas consumed by the current frame and the stake score it would earn.
+	/// See [`pallet_contracts_uapi::HostFn::frame_stake`].
+	#[unstable]
+	fn frame_stake(ctx: _, memory: _, out_ptr: u32, out_len_ptr: u32) -> Result<u32, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::FrameStake)?;
+		let gas_consumed = ctx.ext.gas_meter().gas_consumed().ref_time();
+		let contract = ctx.ext.address().clone();
+		match <E::T as Config>::ContractStake::frame_stake_score(&contract, gas_consumed) {
+			Ok(stake_score) => {
+				ctx.write_sandbox_output(
+					memory,
+					out_ptr,
+					out_len_ptr,
+					&(gas_consumed, stake_score).encode(),
+					false,
+					already_charged,
+				)?;
+				Ok(0)
+			},
+			Err(err) => Runtime::<E>::stake_error_code(err),
+		}
+	}
 }
@@ -82,6 +82,8 @@ pub trait WeightInfo {
 	fn v14_migration_step() -> Weight;
 	fn v15_migration_step() -> Weight;
 	fn v16_migration_step() -> Weight;
+	fn v17_migration_step() -> Weight;
+	fn v18_migration_step() -> Weight;
 	fn migration_noop() -> Weight;
 	fn migrate() -> Weight;
 	fn on_runtime_upgrade_noop() -> Weight;
@@ -152,6 +154,10 @@ pub trait WeightInfo {
 	fn seal_reentrance_count() -> Weight;
 	fn seal_account_reentrance_count() -> Weight;
 	fn seal_instantiation_nonce() -> Weight;
+	fn seal_stake_info() -> Weight;
+	fn seal_delegate_info() -> Weight;
+	fn seal_delegate() -> Weight;
+	fn seal_frame_stake() -> Weight;
 	fn instr_i64_load_store(r: u32, ) -> Weight;
 }
 
@@ -302,6 +308,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Contracts::ContractInfoOf` (r:2 w:0)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55305ef396c75a1f4efc53db3e6dfbceddb1` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55305ef396c75a1f4efc53db3e6dfbceddb1` (r:1 w:1)
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530d9cae639cab769bddf819a01b5bacb07` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530d9cae639cab769bddf819a01b5bacb07` (r:1 w:1)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	fn v17_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `332`
+		//  Estimated: `6272`
+		// Minimum execution time: 502_781_000 picoseconds.
+		Weight::from_parts(537_421_000, 6272)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530d9cae639cab769bddf819a01b5bacb07` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530d9cae639cab769bddf819a01b5bacb07` (r:1 w:0)
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55305ef396c75a1f4efc53db3e6dfbceddb1` (r:2 w:1)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55305ef396c75a1f4efc53db3e6dfbceddb1` (r:2 w:1)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530c154c37976a59503db765514263720ee` (r:0 w:1)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530c154c37976a59503db765514263720ee` (r:0 w:1)
+	fn v18_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `532`
+		//  Estimated: `6472`
+		// Minimum execution time: 33_181_000 picoseconds.
+		Weight::from_parts(38_762_000, 6472)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:1)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	fn migration_noop() -> Weight {
@@ -1136,6 +1178,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(3_005_000, 1567)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `Measured`)
+	fn seal_stake_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3656`
+		// Minimum execution time: 16_676_000 picoseconds.
+		Weight::from_parts(23_416_000, 3656)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `Measured`)
+	fn seal_delegate_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3656`
+		// Minimum execution time: 16_087_000 picoseconds.
+		Weight::from_parts(21_009_000, 3656)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `Measured`)
+	fn seal_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3656`
+		// Minimum execution time: 17_477_000 picoseconds.
+		Weight::from_parts(19_322_000, 3656)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `Measured`)
+	fn seal_frame_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3618`
+		// Minimum execution time: 12_915_000 picoseconds.
+		Weight::from_parts(16_083_000, 3618)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
 	/// The range of component `r` is `[0, 5000]`.
 	fn instr_i64_load_store(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1294,6 +1376,42 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Contracts::ContractInfoOf` (r:2 w:0)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55305ef396c75a1f4efc53db3e6dfbceddb1` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55305ef396c75a1f4efc53db3e6dfbceddb1` (r:1 w:1)
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530d9cae639cab769bddf819a01b5bacb07` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530d9cae639cab769bddf819a01b5bacb07` (r:1 w:1)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	fn v17_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `332`
+		//  Estimated: `6272`
+		// Minimum execution time: 502_781_000 picoseconds.
+		Weight::from_parts(537_421_000, 6272)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530d9cae639cab769bddf819a01b5bacb07` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530d9cae639cab769bddf819a01b5bacb07` (r:1 w:0)
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55305ef396c75a1f4efc53db3e6dfbceddb1` (r:2 w:1)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc55305ef396c75a1f4efc53db3e6dfbceddb1` (r:2 w:1)
+	/// Storage: `Contracts::ContractInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::ContractInfoOf` (`max_values`: None, `max_size`: Some(1795), added: 4270, mode: `Measured`)
+	/// Storage: `Contracts::CodeInfoOf` (r:1 w:0)
+	/// Proof: `Contracts::CodeInfoOf` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `Measured`)
+	/// Storage: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530c154c37976a59503db765514263720ee` (r:0 w:1)
+	/// Proof: UNKNOWN KEY `0x4342193e496fab7ec59d615ed0dc5530c154c37976a59503db765514263720ee` (r:0 w:1)
+	fn v18_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `532`
+		//  Estimated: `6472`
+		// Minimum execution time: 33_181_000 picoseconds.
+		Weight::from_parts(38_762_000, 6472)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:1)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	fn migration_noop() -> Weight {
@@ -2128,6 +2246,46 @@ impl WeightInfo for () {
 		Weight::from_parts(3_005_000, 1567)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `Measured`)
+	fn seal_stake_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3656`
+		// Minimum execution time: 16_676_000 picoseconds.
+		Weight::from_parts(23_416_000, 3656)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `Measured`)
+	fn seal_delegate_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3656`
+		// Minimum execution time: 16_087_000 picoseconds.
+		Weight::from_parts(21_009_000, 3656)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `Measured`)
+	fn seal_delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3656`
+		// Minimum execution time: 17_477_000 picoseconds.
+		Weight::from_parts(19_322_000, 3656)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `Measured`)
+	fn seal_frame_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3618`
+		// Minimum execution time: 12_915_000 picoseconds.
+		Weight::from_parts(16_083_000, 3618)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
 	/// The range of component `r` is `[0, 5000]`.
 	fn instr_i64_load_store(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -11,7 +11,7 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use crate::{CallFlags, Result, ReturnFlags};
+use crate::{pocs, CallFlags, Result, ReturnFlags};
 use paste::paste;
 
 #[cfg(target_arch = "wasm32")]
@@ -408,6 +408,46 @@ pub trait HostFn: private::Sealed {
 	/// - `topics`: The topics list encoded as `Vec<T::Hash>`. It can't contain duplicates.
 	fn deposit_event(topics: &[u8], data: &[u8]);
 
+	/// Delegate the stake of a contract owned by the currently executing contract.
+	///
+	/// # Parameters
+	///
+	/// - `contract`: The contract whose stake is delegated. Should be decodable as an
+	///   `T::AccountId`. Traps otherwise.
+	/// - `delegate_to`: The validator to delegate to. Should be decodable as an `T::AccountId`.
+	///   Traps otherwise.
+	///
+	/// # Errors
+	///
+	/// Returns the [`pocs::PocsError`] the stake provider rejected the delegation with.
+	///
+	/// # Unstable
+	///
+	/// Only available on chains enabling the unstable interface. Behaviour can change without
+	/// further notice.
+	fn delegate(contract: &[u8], delegate_to: &[u8]) -> pocs::Result<()>;
+
+	/// Stores the SCALE encoded delegate information of a contract into the supplied buffer.
+	///
+	/// If the available space in `output` is less than the size of the value a trap is triggered.
+	///
+	/// # Parameters
+	///
+	/// - `contract`: The contract to query. Should be decodable as an `T::AccountId`. Traps
+	///   otherwise.
+	/// - `output`: A reference to the output data buffer to write the delegate information.
+	///
+	/// # Errors
+	///
+	/// Returns the [`pocs::PocsError`] the stake provider failed with, e.g.
+	/// [`pocs::PocsError::NoStakeExists`].
+	///
+	/// # Unstable
+	///
+	/// Only available on chains enabling the unstable interface. Behaviour can change without
+	/// further notice.
+	fn delegate_info(contract: &[u8], output: &mut &mut [u8]) -> pocs::Result<()>;
+
 	/// Recovers the ECDSA public key from the given message hash and signature.
 	///
 	/// Writes the public key into the given output buffer.
@@ -441,6 +481,23 @@ pub trait HostFn: private::Sealed {
 	/// - [EcdsaRecoveryFailed][`crate::ReturnErrorCode::EcdsaRecoveryFailed]
 	fn ecdsa_to_eth_address(pubkey: &[u8; 33], output: &mut [u8; 20]) -> Result;
 
+	/// Returns the gas consumed by the current frame so far and the stake score it would earn.
+	///
+	/// The gas includes nested frames. The stake score is computed at the contract's current
+	/// reputation, the same way the stake provider computes it once the frame finished, and is
+	/// zero unless the contract is delegated.
+	///
+	/// # Errors
+	///
+	/// Returns the [`pocs::PocsError`] the stake provider failed with, e.g.
+	/// [`pocs::PocsError::NoStakeExists`] during the contract's instantiation.
+	///
+	/// # Unstable
+	///
+	/// Only available on chains enabling the unstable interface. Behaviour can change without
+	/// further notice.
+	fn frame_stake() -> pocs::Result<pocs::FrameStake>;
+
 	/// Stores the weight left into the supplied buffer.
 	///
 	/// Equivalent to the newer [`Self::gas_left_v1`] version but
@@ -745,6 +802,27 @@ pub trait HostFn: private::Sealed {
 	/// - [Sr25519VerifyFailed][`crate::ReturnErrorCode::Sr25519VerifyFailed]
 	fn sr25519_verify(signature: &[u8; 64], message: &[u8], pub_key: &[u8; 32]) -> Result;
 
+	/// Stores the SCALE encoded stake information of a contract into the supplied buffer.
+	///
+	/// If the available space in `output` is less than the size of the value a trap is triggered.
+	///
+	/// # Parameters
+	///
+	/// - `contract`: The contract to query. Should be decodable as an `T::AccountId`. Traps
+	///   otherwise.
+	/// - `output`: A reference to the output data buffer to write the stake information.
+	///
+	/// # Errors
+	///
+	/// Returns the [`pocs::PocsError`] the stake provider failed with, e.g.
+	/// [`pocs::PocsError::NoStakeExists`].
+	///
+	/// # Unstable
+	///
+	/// Only available on chains enabling the unstable interface. Behaviour can change without
+	/// further notice.
+	fn stake_info(contract: &[u8], output: &mut &mut [u8]) -> pocs::Result<()>;
+
 	/// Retrieve and remove the value under the given key from storage.
 	///
 	/// # Parameters
@@ -14,7 +14,7 @@
 use super::{
 	extract_from_slice, ptr_len_or_sentinel, ptr_or_sentinel, CallFlags, HostFn, HostFnImpl, Result,
 };
-use crate::{ReturnCode, ReturnFlags};
+use crate::{pocs, ReturnCode, ReturnFlags};
 
 mod sys {
 	use super::ReturnCode;
@@ -75,6 +75,8 @@ mod sys {
 
 		pub fn debug_message(str_ptr: *const u8, str_len: u32) -> ReturnCode;
 
+		pub fn delegate(contract_ptr: *const u8, delegate_to_ptr: *const u8) -> u32;
+
 		pub fn delegate_call(
 			flags: u32,
 			code_hash_ptr: *const u8,
@@ -84,6 +86,12 @@ mod sys {
 			output_len_ptr: *mut u32,
 		) -> ReturnCode;
 
+		pub fn delegate_info(
+			contract_ptr: *const u8,
+			output_ptr: *mut u8,
+			output_len_ptr: *mut u32,
+		) -> u32;
+
 		pub fn deposit_event(
 			topics_ptr: *const u8,
 			topics_len: u32,
@@ -99,6 +107,8 @@ mod sys {
 
 		pub fn ecdsa_to_eth_address(public_key_ptr: *const u8, output_ptr: *mut u8) -> ReturnCode;
 
+		pub fn frame_stake(output_ptr: *mut u8, output_len_ptr: *mut u32) -> u32;
+
 		pub fn gas_left(output_ptr: *mut u8, output_len_ptr: *mut u32);
 
 		pub fn get_storage(
@@ -158,6 +168,12 @@ mod sys {
 			message_ptr: *const u8,
 		) -> ReturnCode;
 
+		pub fn stake_info(
+			contract_ptr: *const u8,
+			output_ptr: *mut u8,
+			output_len_ptr: *mut u32,
+		) -> u32;
+
 		pub fn take_storage(
 			key_ptr: *const u8,
 			key_len: u32,
@@ -901,6 +917,35 @@ impl HostFn for HostFnImpl {
 		unsafe { sys::reentrance_count() }
 	}
 
+	fn stake_info(contract: &[u8], output: &mut &mut [u8]) -> pocs::Result<()> {
+		let mut output_len = output.len() as u32;
+		let ret_code =
+			unsafe { sys::stake_info(contract.as_ptr(), output.as_mut_ptr(), &mut output_len) };
+		extract_from_slice(output, output_len as usize);
+		pocs::PocsError::from_code(ret_code)
+	}
+
+	fn delegate_info(contract: &[u8], output: &mut &mut [u8]) -> pocs::Result<()> {
+		let mut output_len = output.len() as u32;
+		let ret_code =
+			unsafe { sys::delegate_info(contract.as_ptr(), output.as_mut_ptr(), &mut output_len) };
+		extract_from_slice(output, output_len as usize);
+		pocs::PocsError::from_code(ret_code)
+	}
+
+	fn delegate(contract: &[u8], delegate_to: &[u8]) -> pocs::Result<()> {
+		let ret_code = unsafe { sys::delegate(contract.as_ptr(), delegate_to.as_ptr()) };
+		pocs::PocsError::from_code(ret_code)
+	}
+
+	fn frame_stake() -> pocs::Result<pocs::FrameStake> {
+		let mut output = [0u8; 24];
+		let mut output_len = output.len() as u32;
+		let ret_code = unsafe { sys::frame_stake(output.as_mut_ptr(), &mut output_len) };
+		pocs::PocsError::from_code(ret_code)?;
+		pocs::decode_all(&output[..output_len as usize]).ok_or(pocs::PocsError::DecodingFailed)
+	}
+
 	fn xcm_execute(msg: &[u8]) -> Result {
 		let ret_code = unsafe { sys::xcm_execute(msg.as_ptr(), msg.len() as _) };
 		ret_code.into()
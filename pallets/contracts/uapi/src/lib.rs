@@ -21,6 +21,8 @@
 mod flags;
 pub use flags::*;
 
+pub mod pocs;
+
 #[cfg(target_arch = "wasm32")]
 mod host;
 
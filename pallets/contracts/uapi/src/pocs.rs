@@ -0,0 +1,393 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed bindings to the chain extensions of the PoCS pallet.
+//!
+//! Accounts are passed as the raw 32 byte account IDs of the runtime. A rejected request is
+//! reported as a [`PocsError`], leaving it to the contract whether to trap.
+//!
+//! The unstable [`HostFn::stake_info`](crate::HostFn::stake_info),
+//! [`HostFn::delegate_info`](crate::HostFn::delegate_info) and
+//! [`HostFn::delegate`](crate::HostFn::delegate) host functions report the same errors and
+//! return the same encodings as the chain extensions. The stake a call earns is read through
+//! [`HostFn::frame_stake`](crate::HostFn::frame_stake).
+
+/// The raw account ID of a contract or validator.
+pub type AccountId = [u8; 32];
+
+/// Chain extension fetching the stake of contracts and validators.
+pub const FETCH_STAKE_INFO: u16 = 1200;
+
+/// Chain extension updating the stake of contracts owned by the calling contract.
+pub const UPDATE_DELEGATE_INFO: u16 = 1300;
+
+/// Function IDs of the PoCS chain extensions.
+pub mod func_id {
+	/// Returns the validator a contract is delegated to, its owner while it isn't delegated.
+	pub const DELEGATE_TO: u16 = 1000;
+	/// Returns the block number a contract was last delegated at.
+	pub const DELEGATE_AT: u16 = 1001;
+	/// Returns the stake score of a contract.
+	pub const STAKE_SCORE: u16 = 1002;
+	/// Returns the reputation of a contract.
+	pub const REPUTATION: u16 = 1003;
+	/// Returns the stake owner of a contract.
+	pub const OWNER: u16 = 1004;
+	/// Delegates a contract owned by the calling contract.
+	pub const DELEGATE: u16 = 1005;
+	/// Hands a contract owned by the calling contract over to a new owner.
+	pub const UPDATE_OWNER: u16 = 1006;
+	/// Returns the whole stake information of a contract.
+	pub const STAKE_INFO: u16 = 1007;
+	/// Returns the whole delegate information of a contract.
+	pub const DELEGATE_INFO: u16 = 1008;
+	/// Returns the number of contracts delegated to a validator.
+	pub const NUM_DELEGATES: u16 = 1009;
+	/// Returns whether a validator is eligible to validate.
+	pub const CAN_VALIDATE: u16 = 1010;
+	/// Returns the effective parameters of the PoCS protocol.
+	pub const PARAMS: u16 = 1011;
+	/// Validates the calling contract, e.g., a staking pool standing as validator.
+	pub const VALIDATE: u16 = 1012;
+	/// Clears the delegation of a contract owned by the calling contract.
+	pub const UNDELEGATE: u16 = 1013;
+	/// Returns the validator a contract is delegated to, if any.
+	pub const DELEGATED_VALIDATOR: u16 = 1014;
+}
+
+/// Returns the ID passed to `call_chain_extension` for a function of a chain extension.
+pub const fn chain_extension_id(ext_id: u16, func_id: u16) -> u32 {
+	(ext_id as u32) << 16 | func_id as u32
+}
+
+/// Every error that can be returned by the PoCS chain extensions.
+///
+/// The codes match those of the `PocsReturnCode` of the PoCS pallet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PocsError {
+	/// The contract has no stake.
+	NoStakeExists = 1,
+	/// The contract is already delegated to the given validator.
+	AlreadyDelegated = 2,
+	/// The contract does not meet the minimum reputation.
+	LowReputation = 3,
+	/// The calling contract is not the stake owner of the contract.
+	InvalidContractOwner = 4,
+	/// The contract is already owned by the given account.
+	AlreadyOwner = 5,
+	/// The validator does not have the minimum number of delegates.
+	InsufficientDelegates = 6,
+	/// No contract is delegated to the validator.
+	NoValidatorFound = 7,
+	/// The owner links of the contract's stake form a cycle.
+	StakeOwnerCycle = 8,
+	/// The owner links of the contract's stake exceed the maximum resolvable depth.
+	StakeOwnerDepthExceeded = 9,
+	/// The contract isn't delegated to any validator.
+	NotDelegated = 10,
+	/// Returns if an unknown error was received from the chain extension.
+	///
+	/// Reserved at the end of the range so that it never collides with a future code.
+	Unknown = u32::MAX - 1,
+	/// The output of the chain extension could not be decoded.
+	///
+	/// Reserved at the end of the range so that it never collides with a future code.
+	DecodingFailed = u32::MAX,
+}
+
+impl From<PocsError> for u32 {
+	fn from(error: PocsError) -> u32 {
+		error as u32
+	}
+}
+
+impl PocsError {
+	/// Converts the code returned by a PoCS chain extension into a result.
+	pub fn from_code(code: u32) -> Result<()> {
+		match code {
+			0 => Ok(()),
+			1 => Err(Self::NoStakeExists),
+			2 => Err(Self::AlreadyDelegated),
+			3 => Err(Self::LowReputation),
+			4 => Err(Self::InvalidContractOwner),
+			5 => Err(Self::AlreadyOwner),
+			6 => Err(Self::InsufficientDelegates),
+			7 => Err(Self::NoValidatorFound),
+			8 => Err(Self::StakeOwnerCycle),
+			9 => Err(Self::StakeOwnerDepthExceeded),
+			10 => Err(Self::NotDelegated),
+			_ => Err(Self::Unknown),
+		}
+	}
+}
+
+/// The result of a PoCS chain extension function.
+pub type Result<T> = core::result::Result<T, PocsError>;
+
+/// Decodes the SCALE encoding of a value returned by the PoCS chain extensions.
+///
+/// All returned values have a bounded size, hence no general purpose codec is needed.
+pub trait Decode: Sized {
+	/// Decodes a value from the start of `input`, advancing it past the value.
+	fn decode(input: &mut &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_decode_for_int {
+	( $( $int:ty ),* ) => {
+		$(
+			impl Decode for $int {
+				fn decode(input: &mut &[u8]) -> Option<Self> {
+					<[u8; core::mem::size_of::<$int>()]>::decode(input).map(<$int>::from_le_bytes)
+				}
+			}
+		)*
+	};
+}
+
+impl_decode_for_int!(u32, u64, u128);
+
+impl<const N: usize> Decode for [u8; N] {
+	fn decode(input: &mut &[u8]) -> Option<Self> {
+		if input.len() < N {
+			return None
+		}
+		let (value, rest) = input.split_at(N);
+		*input = rest;
+		value.try_into().ok()
+	}
+}
+
+impl Decode for bool {
+	fn decode(input: &mut &[u8]) -> Option<Self> {
+		match u8::from_le_bytes(<[u8; 1]>::decode(input)?) {
+			0 => Some(false),
+			1 => Some(true),
+			_ => None,
+		}
+	}
+}
+
+impl<V: Decode> Decode for Option<V> {
+	fn decode(input: &mut &[u8]) -> Option<Self> {
+		match u8::from_le_bytes(<[u8; 1]>::decode(input)?) {
+			0 => Some(None),
+			1 => V::decode(input).map(Some),
+			_ => None,
+		}
+	}
+}
+
+/// Decodes a value from the whole `input`.
+pub fn decode_all<V: Decode>(mut input: &[u8]) -> Option<V> {
+	let value = V::decode(&mut input)?;
+	input.is_empty().then_some(value)
+}
+
+/// The stake information of a contract.
+///
+/// `BlockNumber` is the block number type of the runtime, e.g., `u32`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StakeInfo<BlockNumber> {
+	/// The reputation of the contract.
+	pub reputation: u32,
+	/// The block height of the contract's most recent usage.
+	pub blockheight: BlockNumber,
+	/// The stake score of the contract.
+	pub stake_score: u128,
+}
+
+impl<BlockNumber: Decode> Decode for StakeInfo<BlockNumber> {
+	fn decode(input: &mut &[u8]) -> Option<Self> {
+		Some(Self {
+			reputation: Decode::decode(input)?,
+			blockheight: Decode::decode(input)?,
+			stake_score: Decode::decode(input)?,
+		})
+	}
+}
+
+/// The delegate information of a contract.
+///
+/// `BlockNumber` is the block number type of the runtime, e.g., `u32`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelegateInfo<BlockNumber> {
+	/// The stake owner of the contract.
+	pub owner: AccountId,
+	/// The validator the contract is delegated to, `None` while it isn't delegated.
+	pub delegate_to: Option<AccountId>,
+	/// The block number the contract was last delegated at.
+	pub delegate_at: BlockNumber,
+}
+
+impl<BlockNumber: Decode> Decode for DelegateInfo<BlockNumber> {
+	fn decode(input: &mut &[u8]) -> Option<Self> {
+		Some(Self {
+			owner: Decode::decode(input)?,
+			delegate_to: Decode::decode(input)?,
+			delegate_at: Decode::decode(input)?,
+		})
+	}
+}
+
+/// The gas consumed by the current frame and the stake score it would earn.
+///
+/// Returned by [`HostFn::frame_stake`](crate::HostFn::frame_stake).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameStake {
+	/// The `ref_time` consumed by the frame so far, including nested frames.
+	pub gas_consumed: u64,
+	/// The stake score the frame would earn at the contract's current reputation.
+	pub stake_score: u128,
+}
+
+impl Decode for FrameStake {
+	fn decode(input: &mut &[u8]) -> Option<Self> {
+		Some(Self { gas_consumed: Decode::decode(input)?, stake_score: Decode::decode(input)? })
+	}
+}
+
+#[cfg(target_arch = "wasm32")]
+mod host_calls {
+	use super::*;
+	use crate::{HostFn, HostFnImpl as api};
+
+	/// Calls a function of a PoCS chain extension.
+	///
+	/// The `output` is truncated to the output of the chain extension on success.
+	pub fn call(
+		ext_id: u16,
+		func_id: u16,
+		input: &[u8],
+		output: Option<&mut &mut [u8]>,
+	) -> Result<()> {
+		PocsError::from_code(api::call_chain_extension(
+			chain_extension_id(ext_id, func_id),
+			input,
+			output,
+		))
+	}
+
+	fn fetch<V: Decode>(func_id: u16, account: &AccountId) -> Result<V> {
+		let mut buffer = [0u8; 128];
+		let output = &mut &mut buffer[..];
+		call(FETCH_STAKE_INFO, func_id, account, Some(output))?;
+		decode_all(&**output).ok_or(PocsError::DecodingFailed)
+	}
+
+	fn update(func_id: u16, contract: &AccountId, target: &AccountId) -> Result<()> {
+		let mut input = [0u8; 64];
+		input[..32].copy_from_slice(contract);
+		input[32..].copy_from_slice(target);
+		call(UPDATE_DELEGATE_INFO, func_id, &input, None)
+	}
+
+	/// Returns the stake score of a contract.
+	pub fn stake_score(contract: &AccountId) -> Result<u128> {
+		fetch(func_id::STAKE_SCORE, contract)
+	}
+
+	/// Returns the reputation of a contract.
+	pub fn reputation(contract: &AccountId) -> Result<u32> {
+		fetch(func_id::REPUTATION, contract)
+	}
+
+	/// Returns the validator a contract is delegated to, its owner while it isn't delegated.
+	pub fn delegate_to(contract: &AccountId) -> Result<AccountId> {
+		fetch(func_id::DELEGATE_TO, contract)
+	}
+
+	/// Returns the validator a contract is delegated to, `None` while it isn't delegated.
+	pub fn delegated_validator(contract: &AccountId) -> Result<Option<AccountId>> {
+		fetch(func_id::DELEGATED_VALIDATOR, contract)
+	}
+
+	/// Returns the stake owner of a contract.
+	pub fn owner(contract: &AccountId) -> Result<AccountId> {
+		fetch(func_id::OWNER, contract)
+	}
+
+	/// Returns the whole stake information of a contract.
+	pub fn stake_info<BlockNumber: Decode>(
+		contract: &AccountId,
+	) -> Result<StakeInfo<BlockNumber>> {
+		fetch(func_id::STAKE_INFO, contract)
+	}
+
+	/// Returns the whole delegate information of a contract.
+	pub fn delegate_info<BlockNumber: Decode>(
+		contract: &AccountId,
+	) -> Result<DelegateInfo<BlockNumber>> {
+		fetch(func_id::DELEGATE_INFO, contract)
+	}
+
+	/// Delegates `contract`, which must be owned by the calling contract, to `target`.
+	pub fn delegate(contract: &AccountId, target: &AccountId) -> Result<()> {
+		update(func_id::DELEGATE, contract, target)
+	}
+
+	/// Hands `contract`, which must be owned by the calling contract, over to `new_owner`.
+	pub fn update_owner(contract: &AccountId, new_owner: &AccountId) -> Result<()> {
+		update(func_id::UPDATE_OWNER, contract, new_owner)
+	}
+
+	/// Clears the delegation of `contract`, which must be owned by the calling contract.
+	pub fn undelegate(contract: &AccountId) -> Result<()> {
+		call(UPDATE_DELEGATE_INFO, func_id::UNDELEGATE, contract, None)
+	}
+
+	/// Checks whether the calling contract has enough delegates to validate.
+	pub fn validate() -> Result<()> {
+		call(UPDATE_DELEGATE_INFO, func_id::VALIDATE, &[], None)
+	}
+}
+
+#[cfg(target_arch = "wasm32")]
+pub use host_calls::*;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn error_codes_are_stable() {
+		let codes = [
+			(PocsError::NoStakeExists, 1),
+			(PocsError::AlreadyDelegated, 2),
+			(PocsError::LowReputation, 3),
+			(PocsError::InvalidContractOwner, 4),
+			(PocsError::AlreadyOwner, 5),
+			(PocsError::InsufficientDelegates, 6),
+			(PocsError::NoValidatorFound, 7),
+			(PocsError::StakeOwnerCycle, 8),
+			(PocsError::StakeOwnerDepthExceeded, 9),
+			(PocsError::NotDelegated, 10),
+			(PocsError::Unknown, u32::MAX - 1),
+			(PocsError::DecodingFailed, u32::MAX),
+		];
+		for (error, code) in codes {
+			assert_eq!(u32::from(error), code);
+		}
+		// Codes of the chain extension decode to the error of the same code
+		for (error, code) in &codes[..10] {
+			assert_eq!(PocsError::from_code(*code), Err(*error));
+		}
+		assert_eq!(PocsError::from_code(0), Ok(()));
+		// Codes the chain extension never returns are unknown
+		assert_eq!(PocsError::from_code(11), Err(PocsError::Unknown));
+		assert_eq!(PocsError::from_code(u32::MAX), Err(PocsError::Unknown));
+	}
+}
@@ -0,0 +1,314 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for the PoCS pallet.
+//!
+//! The `fetch_*` and `delegate_request_rejected` benchmarks measure the chain extension
+//! functions of [`crate::chain_ext`] and the [`pallet_contracts::ContractStake`] implementation,
+//! which are not dispatchable.
+
+use super::*;
+
+use crate::stake::{
+	DelegateInfo, PendingStake, PocsParams, StakeInfo, ValidateRequest, MIN_DELEGATES,
+	MIN_REPUTATION, REPUTATION_FACTOR,
+};
+#[allow(unused)]
+use crate::Pallet as Pocs;
+use alloc::vec::Vec;
+use codec::Encode;
+use frame_benchmarking::v2::*;
+use frame_support::BoundedVec;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// Seeds the stake of a contract owned by `owner` and delegated to `delegate_to`, if any.
+fn seed_stake<T: Config>(
+	owner: &T::AccountId,
+	delegate_to: Option<&T::AccountId>,
+	reputation: u32,
+) -> T::AccountId {
+	seed_stake_at::<T>(0, owner, delegate_to, reputation)
+}
+
+/// Seeds the stake of the `index`th contract owned by `owner` and delegated to `delegate_to`,
+/// if any.
+fn seed_stake_at<T: Config>(
+	index: u32,
+	owner: &T::AccountId,
+	delegate_to: Option<&T::AccountId>,
+	reputation: u32,
+) -> T::AccountId {
+	let contract: T::AccountId = account("contract", index, SEED);
+	DelegateRequest::<T>::seed(&contract, owner, delegate_to, reputation, 1_000);
+	contract
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn fetch_delegate_to() {
+		let owner: T::AccountId = whitelisted_caller();
+		let contract = seed_stake::<T>(&owner, None, MIN_REPUTATION);
+		let result;
+		#[block]
+		{
+			result = DelegateInfo::<T>::get(&contract).map(|info| info.delegate_to().encode());
+		}
+		assert!(result.is_ok());
+	}
+
+	#[benchmark]
+	fn fetch_delegate_at() {
+		let owner: T::AccountId = whitelisted_caller();
+		let contract = seed_stake::<T>(&owner, None, MIN_REPUTATION);
+		let result;
+		#[block]
+		{
+			result = DelegateInfo::<T>::get(&contract).map(|info| info.delegate_at().encode());
+		}
+		assert!(result.is_ok());
+	}
+
+	#[benchmark]
+	fn fetch_stake_score() {
+		let owner: T::AccountId = whitelisted_caller();
+		let contract = seed_stake::<T>(&owner, None, MIN_REPUTATION);
+		let result;
+		#[block]
+		{
+			result = StakeInfo::<T>::get(&contract).map(|info| info.stake_score().encode());
+		}
+		assert!(result.is_ok());
+	}
+
+	#[benchmark]
+	fn fetch_reputation() {
+		let owner: T::AccountId = whitelisted_caller();
+		let contract = seed_stake::<T>(&owner, None, MIN_REPUTATION);
+		let result;
+		#[block]
+		{
+			result = StakeInfo::<T>::get(&contract).map(|info| info.reputation().encode());
+		}
+		assert!(result.is_ok());
+	}
+
+	#[benchmark]
+	fn fetch_owner() {
+		let owner: T::AccountId = whitelisted_caller();
+		let contract = seed_stake::<T>(&owner, None, MIN_REPUTATION);
+		let result;
+		#[block]
+		{
+			result = DelegateInfo::<T>::get(&contract).map(|info| info.owner().encode());
+		}
+		assert!(result.is_ok());
+	}
+
+	#[benchmark]
+	fn fetch_stake_info() {
+		let owner: T::AccountId = whitelisted_caller();
+		let contract = seed_stake::<T>(&owner, None, MIN_REPUTATION);
+		let result;
+		#[block]
+		{
+			result = StakeInfo::<T>::get(&contract).map(|info| info.encode());
+		}
+		assert!(result.is_ok());
+	}
+
+	#[benchmark]
+	fn fetch_delegate_info() {
+		let owner: T::AccountId = whitelisted_caller();
+		let contract = seed_stake::<T>(&owner, None, MIN_REPUTATION);
+		let result;
+		#[block]
+		{
+			result = DelegateInfo::<T>::get(&contract).map(|info| info.encode());
+		}
+		assert!(result.is_ok());
+	}
+
+	#[benchmark]
+	fn fetch_num_delegates() {
+		let validator: T::AccountId = account("validator", 0, SEED);
+		ValidatorInfoMap::<T>::insert(&validator, MIN_DELEGATES);
+		let result;
+		#[block]
+		{
+			result =
+				ValidateRequest::<T>::get(&validator).map(|num_delegates| num_delegates.encode());
+		}
+		assert!(result.is_ok());
+	}
+
+	#[benchmark]
+	fn fetch_can_validate() {
+		let validator: T::AccountId = account("validator", 0, SEED);
+		ValidatorInfoMap::<T>::insert(&validator, MIN_DELEGATES);
+		let result;
+		#[block]
+		{
+			result = ValidateRequest::<T>::can_validate(&validator).encode();
+		}
+		assert_eq!(result, true.encode());
+	}
+
+	#[benchmark]
+	fn fetch_params() {
+		let result;
+		#[block]
+		{
+			result = PocsParams::<T>::get().encode();
+		}
+		assert!(!result.is_empty());
+	}
+
+	#[benchmark]
+	fn fetch_frame_stake_score() {
+		let owner: T::AccountId = whitelisted_caller();
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let contract = seed_stake::<T>(&owner, Some(&validator), MIN_REPUTATION);
+		let result;
+		#[block]
+		{
+			result = StakeRequest::<T>::frame_stake_score(&contract, &1_000);
+		}
+		assert!(result.is_ok());
+	}
+
+	// Worst case: the contract moves from one validator to another, updating both counts.
+	#[benchmark]
+	fn delegate() {
+		let owner: T::AccountId = whitelisted_caller();
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let new_validator: T::AccountId = account("validator", 1, SEED);
+		let contract = seed_stake::<T>(&owner, Some(&validator), MIN_REPUTATION);
+
+		#[extrinsic_call]
+		delegate(RawOrigin::Signed(owner), contract.clone(), new_validator.clone());
+
+		assert_eq!(
+			DelegateInfo::<T>::get(&contract).unwrap().delegate_to(),
+			Some(new_validator.clone())
+		);
+		assert!(ValidatorInfoMap::<T>::get(&validator).is_none());
+		assert_eq!(ValidatorInfoMap::<T>::get(&new_validator), Some(1));
+	}
+
+	// Worst case: the contract is delegated and handed to its validator, keeping the delegation.
+	#[benchmark]
+	fn update_owner() {
+		let owner: T::AccountId = whitelisted_caller();
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let contract = seed_stake::<T>(&owner, Some(&validator), MIN_REPUTATION);
+
+		#[extrinsic_call]
+		update_owner(RawOrigin::Signed(owner), contract.clone(), validator.clone());
+
+		assert_eq!(DelegateInfo::<T>::get(&contract).unwrap().owner(), validator);
+		assert_eq!(ValidatorInfoMap::<T>::get(&validator), Some(1));
+	}
+
+	// Worst case: every contract moves from a validator of its own to another one of its own,
+	// updating both counts.
+	#[benchmark]
+	fn delegate_batch(n: Linear<1, { T::MaxDelegateBatch::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let validators: Vec<(T::AccountId, T::AccountId)> = (0..n)
+			.map(|i| (account("validator", 2 * i, SEED), account("validator", 2 * i + 1, SEED)))
+			.collect();
+		let delegations: Vec<_> = (0..n)
+			.zip(&validators)
+			.map(|(i, (validator, new_validator))| {
+				(
+					seed_stake_at::<T>(i, &owner, Some(validator), MIN_REPUTATION),
+					new_validator.clone(),
+				)
+			})
+			.collect();
+		let delegations = BoundedVec::try_from(delegations).unwrap();
+
+		#[extrinsic_call]
+		delegate_batch(RawOrigin::Signed(owner), delegations, DelegateBatchMode::AllOrNothing);
+
+		for (validator, new_validator) in validators {
+			assert!(ValidatorInfoMap::<T>::get(&validator).is_none());
+			assert_eq!(ValidatorInfoMap::<T>::get(&new_validator), Some(1));
+		}
+	}
+
+	// Worst case: the validator loses its last delegate and is removed.
+	#[benchmark]
+	fn undelegate() {
+		let owner: T::AccountId = whitelisted_caller();
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let contract = seed_stake::<T>(&owner, Some(&validator), MIN_REPUTATION);
+
+		#[extrinsic_call]
+		undelegate(RawOrigin::Signed(owner), contract.clone());
+
+		assert_eq!(DelegateInfo::<T>::get(&contract).unwrap().delegate_to(), None);
+		assert!(ValidatorInfoMap::<T>::get(&validator).is_none());
+	}
+
+	// Worst case: every check but the reputation passes.
+	#[benchmark]
+	fn delegate_request_rejected() {
+		let owner: T::AccountId = whitelisted_caller();
+		let validator: T::AccountId = account("validator", 0, SEED);
+		let contract = seed_stake::<T>(&owner, None, REPUTATION_FACTOR);
+		let result;
+		#[block]
+		{
+			result = DelegateRequest::<T>::delegate(&owner, &contract, &validator);
+		}
+		assert_eq!(result, Err(Error::<T>::LowReputation.into()));
+	}
+
+	// Worst case: every contract is still staked and listed in the summary.
+	#[benchmark]
+	fn summarize(n: Linear<1, { T::MaxStakeSummary::get() }>) {
+		let owner: T::AccountId = whitelisted_caller();
+		let validator: T::AccountId = account("validator", 0, SEED);
+		for i in 0..n {
+			let contract = seed_stake_at::<T>(i, &owner, Some(&validator), MIN_REPUTATION);
+			PendingStakeMap::<T>::insert(&contract, PendingStake::default().add(&1_000));
+		}
+
+		#[block]
+		{
+			StakeRequest::<T>::summarize(n);
+		}
+
+		assert_eq!(PendingStakeMap::<T>::iter().count(), 0);
+	}
+
+	#[benchmark]
+	fn validate() {
+		let validator: T::AccountId = whitelisted_caller();
+		ValidatorInfoMap::<T>::insert(&validator, MIN_DELEGATES);
+
+		#[extrinsic_call]
+		validate(RawOrigin::Signed(validator));
+	}
+
+	impl_benchmark_test_suite!(Pocs, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
+}
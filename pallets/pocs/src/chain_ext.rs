@@ -0,0 +1,383 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// This file is utilized for Proof of Contract Stake Protocol (PoCS).
+//
+use crate::{
+	stake::{DelegateInfo, DelegateRequest, PocsParams, StakeInfo, ValidateRequest},
+	Config as PocsConfig, Error, Pallet as Pocs, WeightInfo, LOG_TARGET,
+};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::traits::PalletInfoAccess;
+use log::{debug, error};
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, RegisteredChainExtension, RetVal,
+};
+use sp_core::crypto::UncheckedFrom;
+use sp_runtime::{DispatchError, ModuleError};
+
+/// Codes returned by the PoCS chain extensions through [`RetVal::Converging`].
+///
+/// A function failing with an [`Error`] of this pallet returns the code of the error
+/// instead of trapping the calling contract, which may then handle it. The output buffer is
+/// left untouched in that case. Any other error, e.g., running out of gas, still traps.
+///
+/// The codes are part of the contract ABI and never change. New errors are appended.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PocsReturnCode {
+	/// The function succeeded.
+	Success = 0,
+	/// See [`Error::NoStakeExists`].
+	NoStakeExists = 1,
+	/// See [`Error::AlreadyDelegated`].
+	AlreadyDelegated = 2,
+	/// See [`Error::LowReputation`].
+	LowReputation = 3,
+	/// See [`Error::InvalidContractOwner`].
+	InvalidContractOwner = 4,
+	/// See [`Error::AlreadyOwner`].
+	AlreadyOwner = 5,
+	/// See [`Error::InsufficientDelegates`].
+	InsufficientDelegates = 6,
+	/// See [`Error::NoValidatorFound`].
+	NoValidatorFound = 7,
+	/// See [`Error::StakeOwnerCycle`].
+	StakeOwnerCycle = 8,
+	/// See [`Error::StakeOwnerDepthExceeded`].
+	StakeOwnerDepthExceeded = 9,
+	/// See [`Error::NotDelegated`].
+	NotDelegated = 10,
+}
+
+impl From<PocsReturnCode> for u32 {
+	fn from(code: PocsReturnCode) -> u32 {
+		code as u32
+	}
+}
+
+impl PocsReturnCode {
+	/// Maps an error of this pallet to its return code.
+	///
+	/// Returns `None` for errors of other pallets, which are not part of the ABI.
+	///
+	pub fn from_error<T: PocsConfig>(error: &DispatchError) -> Option<Self> {
+		let DispatchError::Module(ModuleError { index, error, .. }) = error else { return None };
+		if *index as usize != <Pocs<T> as PalletInfoAccess>::index() {
+			return None
+		}
+		match Error::<T>::decode(&mut &error[..]).ok()? {
+			Error::NoStakeExists => Some(Self::NoStakeExists),
+			Error::AlreadyDelegated => Some(Self::AlreadyDelegated),
+			Error::LowReputation => Some(Self::LowReputation),
+			Error::InvalidContractOwner => Some(Self::InvalidContractOwner),
+			Error::AlreadyOwner => Some(Self::AlreadyOwner),
+			Error::InsufficientDelegates => Some(Self::InsufficientDelegates),
+			Error::NoValidatorFound => Some(Self::NoValidatorFound),
+			Error::StakeOwnerCycle => Some(Self::StakeOwnerCycle),
+			Error::StakeOwnerDepthExceeded => Some(Self::StakeOwnerDepthExceeded),
+			Error::NotDelegated => Some(Self::NotDelegated),
+			_ => None,
+		}
+	}
+
+	/// Converts the outcome of a chain extension function into its return value.
+	///
+	fn ret_val<T: PocsConfig>(result: Result<(), DispatchError>) -> Result<RetVal, DispatchError> {
+		match result {
+			Ok(()) => Ok(RetVal::Converging(Self::Success.into())),
+			Err(e) =>
+				Self::from_error::<T>(&e).map(|code| RetVal::Converging(code.into())).ok_or(e),
+		}
+	}
+}
+
+/// Chain Extension for Fetching Contract's DelegateInfo, StakeInfo
+///
+/// Functions `1000`-`1004` return a single field of a contract's stake, `1007` and `1008` the
+/// whole [`StakeInfo`] and [`DelegateInfo`]. The validator returned by `1000` is the stake
+/// owner while the contract isn't delegated, as before undelegated contracts were represented
+/// as `None`. Function `1014` returns the validator as an `Option`, `None` while the contract
+/// isn't delegated. A contract without stake yields [`PocsReturnCode::NoStakeExists`].
+/// Functions `1009` and `1010` return the delegate count and eligibility of a validator, `1011`
+/// the [`PocsParams`] in effect.
+///
+/// Every function charges its benchmarked weight from [`crate::weights`] before reading storage.
+///
+pub struct FetchStakeInfo<T>(PhantomData<T>);
+
+impl<T> Default for FetchStakeInfo<T> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+/// Register FetchStakeInfo Chain extension id 1200
+///
+impl<T> RegisteredChainExtension<T> for FetchStakeInfo<T>
+where
+	T: PocsConfig,
+	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+	const ID: u16 = 1200;
+}
+
+/// Implementation template provided in [`pallet_contracts::chain_extension`]
+///
+impl<T> ChainExtension<T> for FetchStakeInfo<T>
+where
+	T: PocsConfig,
+	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+	fn call<E: Ext<T = T>>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError> {
+		let func_id = env.func_id();
+		let mut env = env.buf_in_buf_out();
+
+		// Every function but `1011` reads the account ID of a contract or validator as input
+		let result = match func_id {
+			// Get delegate_to of a contract, its owner if it isn't delegated
+			// Field of `crate::stake::DelegateInfo`
+			1000 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_delegate_to())?;
+				let contract_addr: T::AccountId = env.read_as()?;
+				DelegateInfo::<T>::get(&contract_addr).map(|delegate_info| {
+					delegate_info.delegate_to().unwrap_or_else(|| delegate_info.owner()).encode()
+				})
+			},
+			// Get delegate_at of a contract
+			// Field of `crate::stake::DelegateInfo`
+			1001 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_delegate_at())?;
+				let contract_addr: T::AccountId = env.read_as()?;
+				DelegateInfo::<T>::get(&contract_addr)
+					.map(|delegate_info| delegate_info.delegate_at().encode())
+			},
+			// Get stake_score of a contract
+			// Field of `crate::stake::StakeInfo`
+			1002 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_stake_score())?;
+				let contract_addr: T::AccountId = env.read_as()?;
+				StakeInfo::<T>::get(&contract_addr)
+					.map(|stake_info| stake_info.stake_score().encode())
+			},
+			// Get reputation of a contract
+			// Field of [`crate::stake::StakeInfo`]
+			1003 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_reputation())?;
+				let contract_addr: T::AccountId = env.read_as()?;
+				StakeInfo::<T>::get(&contract_addr)
+					.map(|stake_info| stake_info.reputation().encode())
+			},
+			// Get Owner of a contract
+			// Field of [`crate::stake::DelegateInfo`]
+			1004 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_owner())?;
+				let contract_addr: T::AccountId = env.read_as()?;
+				DelegateInfo::<T>::get(&contract_addr)
+					.map(|delegate_info| delegate_info.owner().encode())
+			},
+			// Get StakeInfo of a contract
+			// [`crate::stake::StakeInfo`]
+			1007 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_stake_info())?;
+				let contract_addr: T::AccountId = env.read_as()?;
+				StakeInfo::<T>::get(&contract_addr).map(|stake_info| stake_info.encode())
+			},
+			// Get DelegateInfo of a contract
+			// [`crate::stake::DelegateInfo`]
+			1008 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_delegate_info())?;
+				let contract_addr: T::AccountId = env.read_as()?;
+				DelegateInfo::<T>::get(&contract_addr).map(|delegate_info| delegate_info.encode())
+			},
+			// Get number of contracts delegated to a validator
+			// Value of [`crate::ValidatorInfoMap`]
+			1009 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_num_delegates())?;
+				let validator: T::AccountId = env.read_as()?;
+				ValidateRequest::<T>::get(&validator).map(|num_delegates| num_delegates.encode())
+			},
+			// Get whether a validator is eligible to validate
+			1010 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_can_validate())?;
+				let validator: T::AccountId = env.read_as()?;
+				Ok(ValidateRequest::<T>::can_validate(&validator).encode())
+			},
+			// Get the effective PoCS parameters
+			// [`crate::stake::PocsParams`]
+			1011 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_params())?;
+				Ok(PocsParams::<T>::get().encode())
+			},
+			// Get delegate_to of a contract, `None` if it isn't delegated
+			// Field of `crate::stake::DelegateInfo`
+			1014 => {
+				env.charge_weight(<T as PocsConfig>::WeightInfo::fetch_delegate_to())?;
+				let contract_addr: T::AccountId = env.read_as()?;
+				DelegateInfo::<T>::get(&contract_addr)
+					.map(|delegate_info| delegate_info.delegate_to().encode())
+			},
+			// Handle unknown function IDs
+			_ => {
+				debug!(target: LOG_TARGET, "Called an unregistered `func_id`: {}", func_id);
+				return Err(DispatchError::Other("UnknownFunction"));
+			},
+		};
+		match result {
+			Ok(output) => {
+				env.write(&output, false, None)?;
+				PocsReturnCode::ret_val::<T>(Ok(()))
+			},
+			Err(e) => PocsReturnCode::ret_val::<T>(Err(e)),
+		}
+	}
+}
+
+/// Chain Extension for Updating Delegate of Contract Owned Contracts
+/// and to update stake owner of a contract owned contract
+///
+/// Function 1012 takes no input and validates the calling contract itself, so that contracts
+/// such as staking pools can stand as validators, see [`ValidateRequest::validate`].
+/// Function 1013 takes the address of a contract owned by the calling contract and clears its
+/// delegation, see [`DelegateRequest::undelegate`].
+///
+/// Every function charges the weight of its worst case before reading its input or touching
+/// storage. The difference to a rejected request is refunded and the request's
+/// [`PocsReturnCode`] returned.
+///
+pub struct UpdateDelegateInfo<T>(PhantomData<T>);
+
+impl<T> Default for UpdateDelegateInfo<T> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+/// Register UpdateDelegateInfo Chain extension id 1300
+///
+impl<T> RegisteredChainExtension<T> for UpdateDelegateInfo<T>
+where
+	T: PocsConfig,
+	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+	const ID: u16 = 1300;
+}
+
+/// Implementation template provided in [`pallet_contracts::chain_extension`]
+///
+impl<T> ChainExtension<T> for UpdateDelegateInfo<T>
+where
+	T: PocsConfig,
+	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+	fn call<E: Ext<T = T>>(
+		&mut self,
+		env: Environment<E, InitState>,
+	) -> Result<RetVal, DispatchError> {
+		let func_id = env.func_id();
+		let mut env = env.buf_in_buf_out();
+
+		// Requests 1005 and 1006 read the parameters passed from the environment:
+		//
+		// It includes:
+		// - `contract_addr` - The contract that needs to be updated
+		// - `target_addr` - The target contract for the chain extension function
+		let (charged, rejected, result) = match func_id {
+			1005 => {
+				let charged = env.charge_weight(<T as PocsConfig>::WeightInfo::delegate())?;
+				let (contract_addr, target_addr): (T::AccountId, T::AccountId) = env.read_as()?;
+
+				// Get the current contract that is executing the chain extension
+				// As passing as parameters is unsafe, cause contracts cannot sign transactions
+				// We verify that the contract calling the extension from reading its address from environment
+				let executing_contract = env.ext().address();
+
+				// Execute updating delegate which updates map [`crate::PocsInfoMap`]
+				// The same function call is utilized by [`crate::Pallet::delegate`] for EOA owned contracts delegate update
+				let result = <DelegateRequest<T>>::delegate(
+					executing_contract,
+					&contract_addr,
+					&target_addr,
+				);
+				(charged, <T as PocsConfig>::WeightInfo::delegate_request_rejected(), result)
+			},
+
+			1006 => {
+				let charged = env.charge_weight(<T as PocsConfig>::WeightInfo::update_owner())?;
+				let (contract_addr, target_addr): (T::AccountId, T::AccountId) = env.read_as()?;
+
+				// Get the current contract that is executing the chain extension
+				// As passing as parameters is unsafe, cause contracts cannot sign transactions
+				// We verify that the contract calling the extension from reading its address from environment
+				let executing_contract = env.ext().address();
+
+				// Execute updating stake owner of a contract which updates map [`crate::PocsInfoMap`]
+				// The same function call is utilized by [`crate::Pallet::update_owner`] for EOA owned contracts owner update
+				let result = <DelegateRequest<T>>::update_stake_owner(
+					executing_contract,
+					&contract_addr,
+					&target_addr,
+				);
+				(charged, <T as PocsConfig>::WeightInfo::delegate_request_rejected(), result)
+			},
+
+			1012 => {
+				let charged = env.charge_weight(<T as PocsConfig>::WeightInfo::validate())?;
+
+				// The executing contract stands as validator, e.g., a contract run staking pool
+				// The same function call is utilized by [`crate::Pallet::validate`] for EOA validators
+				let executing_contract = env.ext().address();
+				let result = <ValidateRequest<T>>::validate(executing_contract);
+				(charged, <T as PocsConfig>::WeightInfo::validate(), result)
+			},
+
+			1013 => {
+				let charged = env.charge_weight(<T as PocsConfig>::WeightInfo::undelegate())?;
+				let contract_addr: T::AccountId = env.read_as()?;
+
+				// Only the executing contract may undelegate the contracts it owns
+				// The same function call is utilized by [`crate::Pallet::undelegate`] for EOA owned contracts
+				let executing_contract = env.ext().address();
+				let result = <DelegateRequest<T>>::undelegate(executing_contract, &contract_addr);
+				(charged, <T as PocsConfig>::WeightInfo::delegate_request_rejected(), result)
+			},
+
+			// Handle unknown function IDs
+			_ => {
+				debug!(target: LOG_TARGET, "Called an unregistered `func_id`: {}", func_id);
+				return Err(DispatchError::Other("UnknownFunction"));
+			},
+		};
+		// Requests are validated before any storage is written, hence a rejected request
+		// leaves no changes behind even though the calling contract does not trap.
+		if let Err(e) = &result {
+			// Rejections are returned to the calling contract, only other errors are failures
+			match PocsReturnCode::from_error::<T>(e) {
+				Some(code) =>
+					debug!(target: LOG_TARGET, "Request of `func_id` {} rejected: {:?}", func_id, code),
+				None =>
+					error!(target: LOG_TARGET, "Request of `func_id` {} failed: {:?}", func_id, e),
+			}
+			env.adjust_weight(charged, rejected);
+		}
+		PocsReturnCode::ret_val::<T>(result)
+	}
+}
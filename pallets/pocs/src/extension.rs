@@ -0,0 +1,141 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transaction extension recording failed top-level contract calls.
+//!
+//! [`OnContractExecution::on_call_outcome`](pallet_contracts::OnContractExecution) reports
+//! the outcome of every trapped, reverted or successful call frame. A trapped or reverted
+//! `Contracts::call` extrinsic however returns an error, which rolls back all storage changes of
+//! the dispatch, including the recorded failure.
+//! [`RecordCallFailure`] records it again in its post dispatch step, which runs outside of the
+//! dispatch's storage layer.
+
+use crate::{stake::StakeRequest, Config};
+use codec::{Decode, DecodeWithMemTracking, Encode, HasCompact};
+use core::{fmt::Debug, marker::PhantomData};
+use frame_support::{
+	traits::{fungible::Inspect, Get, IsSubType},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	impl_tx_ext_default,
+	traits::{
+		DispatchInfoOf, DispatchOriginOf, Dispatchable, PostDispatchInfoOf, StaticLookup,
+		TransactionExtension,
+	},
+	transaction_validity::TransactionValidityError,
+	DispatchResult,
+};
+
+type RuntimeCallOf<T> = <T as frame_system::Config>::RuntimeCall;
+type BalanceOf<T> = <<T as pallet_contracts::Config>::Currency as Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Records the failure of a `Contracts::call` extrinsic in the
+/// [`FailureInfoMap`](crate::FailureInfoMap) of the called contract.
+///
+/// Only calls dispatched directly by the extrinsic are covered. Calls wrapped by other
+/// dispatchables, e.g. `Utility::batch`, are only recorded if the wrapping call succeeds.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct RecordCallFailure<T>(PhantomData<T>);
+
+impl<T> RecordCallFailure<T> {
+	/// Creates a new `RecordCallFailure` extension.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T> Default for RecordCallFailure<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> core::fmt::Debug for RecordCallFailure<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "RecordCallFailure")
+	}
+}
+
+impl<T: Config + Send + Sync> RecordCallFailure<T> {
+	/// Worst case weight of [`StakeRequest::record_outcome`] including a penalty.
+	fn record_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<RuntimeCallOf<T>> for RecordCallFailure<T>
+where
+	RuntimeCallOf<T>: Dispatchable + IsSubType<pallet_contracts::Call<T>>,
+	<BalanceOf<T> as HasCompact>::Type: Clone + Eq + PartialEq + Debug + TypeInfo + Encode,
+{
+	const IDENTIFIER: &'static str = "RecordCallFailure";
+	type Implicit = ();
+	type Val = ();
+	/// The contract called by the extrinsic, if any.
+	type Pre = Option<T::AccountId>;
+
+	fn weight(&self, call: &RuntimeCallOf<T>) -> Weight {
+		match call.is_sub_type() {
+			Some(pallet_contracts::Call::call { .. }) => Self::record_weight(),
+			_ => Weight::zero(),
+		}
+	}
+
+	fn prepare(
+		self,
+		_val: Self::Val,
+		_origin: &DispatchOriginOf<RuntimeCallOf<T>>,
+		call: &RuntimeCallOf<T>,
+		_info: &DispatchInfoOf<RuntimeCallOf<T>>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(match call.is_sub_type() {
+			Some(pallet_contracts::Call::call { dest, .. }) =>
+				<T as frame_system::Config>::Lookup::lookup(dest.clone()).ok(),
+			_ => None,
+		})
+	}
+
+	fn post_dispatch_details(
+		pre: Self::Pre,
+		_info: &DispatchInfoOf<RuntimeCallOf<T>>,
+		_post_info: &PostDispatchInfoOf<RuntimeCallOf<T>>,
+		_len: usize,
+		result: &DispatchResult,
+	) -> Result<Weight, TransactionValidityError> {
+		let Some(contract) = pre else { return Ok(Weight::zero()) };
+		match result {
+			// Only calls the contract itself trapped or reverted count as failures, others are
+			// e.g. out of gas and could be triggered by anyone at no cost
+			Err(e)
+				if *e == pallet_contracts::Error::<T>::ContractTrapped.into() ||
+					*e == pallet_contracts::Error::<T>::ContractReverted.into() =>
+			{
+				StakeRequest::<T>::record_outcome(&contract, false);
+				Ok(Weight::zero())
+			},
+			// Other calls kept the outcome recorded during execution, if any
+			_ => Ok(Self::record_weight()),
+		}
+	}
+
+	impl_tx_ext_default!(RuntimeCallOf<T>; validate);
+}
@@ -0,0 +1,223 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzz driver for the PoCS state machine.
+//!
+//! Every input is decoded into a sequence of operations (instantiate, call, `delegate`,
+//! `update_owner`, terminate, `validate` and block advancement) which is executed against the
+//! [mock](crate::mock) runtime. The PoCS invariants are checked after every single operation, so
+//! that a failure points at the operation that broke them.
+//!
+//! [`run`] is driven by the seeded tests of this pallet and, with the `fuzz` feature, by the
+//! honggfuzz target in `pallets/pocs/fuzzer`.
+
+use crate::{mock::*, stake::PocsInfo};
+use codec::Encode;
+use frame_support::traits::fungible::Mutate;
+use pallet_contracts::{Code, Determinism};
+use pallet_contracts_fixtures::compile_module;
+use sp_runtime::AccountId32;
+
+/// Accounts taking part in the sequences, as callers, owners and delegates.
+const ACCOUNTS: [AccountId32; 4] = [ALICE, BOB, CHARLIE, DJANGO];
+
+/// Maximum number of operations executed per input.
+#[cfg(not(fuzzing))]
+pub const MAX_STEPS: usize = 64;
+/// Maximum number of operations executed per input.
+#[cfg(fuzzing)]
+pub const MAX_STEPS: usize = 1_024;
+
+/// A single step of the state machine.
+#[derive(Debug)]
+enum Op {
+	Instantiate { caller: AccountId32, terminable: bool },
+	Call { caller: AccountId32, contract: u8 },
+	Delegate { origin: AccountId32, contract: u8, delegate_to: AccountId32 },
+	UpdateOwner { origin: AccountId32, contract: u8, new_owner: AccountId32 },
+	Terminate { caller: AccountId32, contract: u8 },
+	Validate { validator: AccountId32 },
+	AdvanceBlock { blocks: u8 },
+	Undelegate { origin: AccountId32, contract: u8 },
+}
+
+/// Decodes operations from raw fuzzer input. Each operation takes at most four bytes.
+///
+/// Inputs therefore never need more than `MAX_STEPS * 4` bytes.
+struct Input<'a> {
+	data: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+	fn byte(&mut self) -> Option<u8> {
+		let (first, rest) = self.data.split_first()?;
+		self.data = rest;
+		Some(*first)
+	}
+
+	fn account(&mut self) -> Option<AccountId32> {
+		self.byte().map(|b| ACCOUNTS[b as usize % ACCOUNTS.len()].clone())
+	}
+
+	fn op(&mut self) -> Option<Op> {
+		let op = match self.byte()? % 8 {
+			0 => Op::Instantiate { caller: self.account()?, terminable: self.byte()? % 2 == 0 },
+			1 => Op::Call { caller: self.account()?, contract: self.byte()? },
+			2 => Op::Delegate {
+				origin: self.account()?,
+				contract: self.byte()?,
+				delegate_to: self.account()?,
+			},
+			3 => Op::UpdateOwner {
+				origin: self.account()?,
+				contract: self.byte()?,
+				new_owner: self.account()?,
+			},
+			4 => Op::Terminate { caller: self.account()?, contract: self.byte()? },
+			5 => Op::Validate { validator: self.account()? },
+			6 => Op::AdvanceBlock { blocks: self.byte()? % 4 + 1 },
+			_ => Op::Undelegate { origin: self.account()?, contract: self.byte()? },
+		};
+		Some(op)
+	}
+}
+
+/// A deployed contract and whether calling it terminates it.
+struct Deployed {
+	addr: AccountId32,
+	terminable: bool,
+}
+
+/// Picks one of the live contracts, if any.
+fn pick(contracts: &[Deployed], index: u8) -> Option<&Deployed> {
+	(!contracts.is_empty()).then(|| &contracts[index as usize % contracts.len()])
+}
+
+/// Checks the PoCS invariants, panicking with the offending operation on failure.
+fn check_invariants(step: usize, op: &Op, contracts: &[Deployed]) {
+	if let Err(err) = Pocs::do_try_state() {
+		panic!("PoCS invariant violated at step {step} by {op:?}: {err:?}");
+	}
+	for contract in contracts {
+		assert!(
+			<PocsInfo<Test>>::get(&contract.addr).is_ok(),
+			"contract {:?} lost its stake at step {step} by {op:?}",
+			contract.addr,
+		);
+	}
+}
+
+/// Executes the operations decoded from `data` and checks the PoCS invariants after each one.
+pub fn run(data: &[u8]) {
+	let (dummy, _) = compile_module::<Test>("dummy").unwrap();
+	let (self_destruct, _) = compile_module::<Test>("self_destruct").unwrap();
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		// Initialize First Block
+		let mut block = 1;
+		initialize_block(block);
+		// Deposit Balance for Transaction Fees
+		for account in ACCOUNTS.iter() {
+			let _ = Balances::set_balance(account, 1_000_000_000);
+		}
+		// Upload both codes once so that every instantiation only references them
+		let dummy_hash = Contracts::bare_upload_code(ALICE, dummy, None, Determinism::Enforced)
+			.unwrap()
+			.code_hash;
+		let self_destruct_hash =
+			Contracts::bare_upload_code(ALICE, self_destruct, None, Determinism::Enforced)
+				.unwrap()
+				.code_hash;
+
+		let mut input = Input { data };
+		let mut contracts: Vec<Deployed> = Vec::new();
+		let mut salt: u32 = 0;
+		for step in 0..MAX_STEPS {
+			let Some(op) = input.op() else { break };
+			match &op {
+				Op::Instantiate { caller, terminable } => {
+					salt += 1;
+					let code_hash = if *terminable { self_destruct_hash } else { dummy_hash };
+					let result = builder::bare_instantiate(Code::Existing(code_hash))
+						.origin(caller.clone())
+						.salt(salt.encode())
+						.build()
+						.result;
+					if let Ok(result) = result {
+						if !result.result.did_revert() {
+							contracts.push(Deployed {
+								addr: result.account_id,
+								terminable: *terminable,
+							});
+						}
+					}
+				},
+				Op::Call { caller, contract } => {
+					// Calling a terminable contract with input traps instead of terminating it
+					if let Some(contract) = pick(&contracts, *contract) {
+						let _ = builder::bare_call(contract.addr.clone())
+							.origin(caller.clone())
+							.data(vec![1u8; 4])
+							.build();
+					}
+				},
+				Op::Delegate { origin, contract, delegate_to } => {
+					if let Some(contract) = pick(&contracts, *contract) {
+						let _ = Pocs::delegate(
+							RuntimeOrigin::signed(origin.clone()),
+							contract.addr.clone(),
+							delegate_to.clone(),
+						);
+					}
+				},
+				Op::UpdateOwner { origin, contract, new_owner } => {
+					if let Some(contract) = pick(&contracts, *contract) {
+						let _ = Pocs::update_owner(
+							RuntimeOrigin::signed(origin.clone()),
+							contract.addr.clone(),
+							new_owner.clone(),
+						);
+					}
+				},
+				Op::Terminate { caller, contract } => {
+					if let Some(contract) =
+						pick(&contracts, *contract).filter(|contract| contract.terminable)
+					{
+						let _ = builder::bare_call(contract.addr.clone())
+							.origin(caller.clone())
+							.build();
+					}
+					contracts.retain(|contract| Contracts::code_hash(&contract.addr).is_some());
+				},
+				Op::Validate { validator } => {
+					let _ = Pocs::validate(RuntimeOrigin::signed(validator.clone()));
+				},
+				Op::AdvanceBlock { blocks } => {
+					block += *blocks as u64;
+					initialize_block(block);
+				},
+				Op::Undelegate { origin, contract } => {
+					if let Some(contract) = pick(&contracts, *contract) {
+						let _ = Pocs::undelegate(
+							RuntimeOrigin::signed(origin.clone()),
+							contract.addr.clone(),
+						);
+					}
+				},
+			}
+			check_invariants(step, &op, &contracts);
+		}
+	});
+}
@@ -0,0 +1,658 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # PoCS Pallet
+//!
+//! The Proof of Contract Stake (PoCS) pallet turns the gas spent executing contracts into stake.
+//!
+//! ## Overview
+//!
+//! Every contract instantiated through the contracts pallet is staked on its first execution,
+//! owned by its deployer. The gas consumed by later calls accrues to the contract's stake score
+//! once the contract is delegated to a validator, weighted by a reputation that grows with every
+//! block the contract is used in. Validators with enough delegated contracts may validate.
+//!
+//! The pallet implements [`pallet_contracts::OnContractExecution`] and must be configured as the
+//! execution hook of the contracts pallet:
+//!
+//! ```ignore
+//! impl pallet_contracts::Config for Runtime {
+//! 	type OnContractExecution = Pocs;
+//! 	// ...
+//! }
+//! ```
+//!
+//! Contracts query and update their stake through the chain extensions in [`chain_ext`], which
+//! report rejected requests through the codes of [`chain_ext::PocsReturnCode`]. The pallet also
+//! implements [`pallet_contracts::ContractStake`], backing the unstable `stake_info`,
+//! `delegate_info`, `delegate` and `frame_stake` host functions with the same codes.
+//!
+//! Failed `Contracts::call` extrinsics roll back everything recorded during their execution.
+//! Runtimes add [`RecordCallFailure`] to their transaction extensions so that these failures
+//! count towards the failure rate of the called contract.
+//!
+//! ## Dispatchable functions
+//!
+//! * [`Pallet::delegate`] - Delegates a contract to a validator.
+//! * [`Pallet::delegate_batch`] - Delegates several contracts at once.
+//! * [`Pallet::undelegate`] - Returns a delegated contract to its owner.
+//! * [`Pallet::update_owner`] - Hands the stake of a contract over to another account.
+//! * [`Pallet::validate`] - Checks whether a validator is eligible to validate.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod chain_ext;
+pub mod extension;
+pub mod migration;
+pub mod stake;
+pub mod weights;
+
+#[cfg(any(test, feature = "fuzz"))]
+pub mod fuzz;
+#[cfg(any(test, feature = "fuzz"))]
+pub mod mock;
+#[cfg(test)]
+mod tests;
+
+pub use extension::RecordCallFailure;
+pub use pallet::*;
+pub use weights::*;
+
+use alloc::vec::Vec;
+use chain_ext::PocsReturnCode;
+use codec::{Codec, Encode};
+use frame_support::{traits::Get, weights::Weight};
+use pallet_contracts::{
+	execution_hook::{ExecutedFrame, ExportedFunction, OnContractExecution},
+	ContractStake,
+};
+use sp_runtime::DispatchError;
+use stake::{
+	CodeStakeRequest, DelegateBatchMode, DelegateInfo, DelegateRequest, StakeEventMode, StakeInfo,
+	StakeRequest, ValidateRequest,
+};
+
+const LOG_TARGET: &str = "runtime::pocs";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use crate::stake::{CodeStakeInfo, DeploymentBond, FailureInfo, PendingStake, PocsInfo};
+	use alloc::vec::Vec;
+	use frame_support::{dispatch::WithPostDispatchInfo, pallet_prelude::*};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::Perbill;
+
+	/// The in-code storage version.
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_contracts::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The number of blocks over which trapped and reverted executions of a contract are
+		/// tallied before its failure rate is evaluated.
+		#[pallet::constant]
+		type FailureWindow: Get<BlockNumberFor<Self>>;
+
+		/// The failure rate within a [`Config::FailureWindow`] above which a contract's
+		/// reputation is penalized.
+		#[pallet::constant]
+		type FailureThreshold: Get<Perbill>;
+
+		/// The reputation deducted from a contract whose failure rate exceeded
+		/// [`Config::FailureThreshold`].
+		///
+		/// The reputation never drops below the value a contract is instantiated with.
+		#[pallet::constant]
+		type FailurePenalty: Get<u32>;
+
+		/// The share of a contract's reputation that is retained when its code is replaced via
+		/// `seal_set_code_hash` or `set_code`.
+		///
+		/// The reputation never drops below the value a contract is instantiated with.
+		#[pallet::constant]
+		type CodeChangeReputationRetained: Get<Perbill>;
+
+		/// The share of a contract's stake score that is retained when its code is replaced via
+		/// `seal_set_code_hash` or `set_code`.
+		#[pallet::constant]
+		type CodeChangeStakeRetained: Get<Perbill>;
+
+		/// Whether the gas spent by a contract's constructor is recorded as its deployment
+		/// bond.
+		///
+		/// The bond is kept apart from the stake score and is neither reset by delegation nor
+		/// transferred with the stake ownership.
+		#[pallet::constant]
+		type DeploymentBondEnabled: Get<bool>;
+
+		/// The number of blocks over which a deployment bond linearly decays to zero.
+		///
+		/// A value of zero keeps the bond for the lifetime of the contract.
+		#[pallet::constant]
+		type DeploymentBondDecay: Get<BlockNumberFor<Self>>;
+
+		/// The deployment bond at which a contract may be delegated before reaching the minimum
+		/// reputation.
+		///
+		/// A value of zero disables maturity through the deployment bond.
+		#[pallet::constant]
+		type DeploymentBondMaturity: Get<u128>;
+
+		/// Whether the stake of a contract instantiated by another contract is owned by the
+		/// transaction origin instead of the immediate caller.
+		#[pallet::constant]
+		type StakeOwnerIsOrigin: Get<bool>;
+
+		/// How stake score updates of delegated contracts are announced.
+		///
+		/// Depositing an event per frame fills `System::Events` in busy blocks. A summary per
+		/// transaction or per block reduces this to a single event. In the latter case the
+		/// summary is deposited in `on_finalize`, covering at most [`Config::MaxStakeSummary`]
+		/// contracts.
+		#[pallet::constant]
+		type StakeEventMode: Get<StakeEventMode>;
+
+		/// The maximum number of delegations in a single [`Pallet::delegate_batch`] call.
+		#[pallet::constant]
+		type MaxDelegateBatch: Get<u32>;
+
+		/// The maximum number of contracts covered by the summary deposited per block.
+		///
+		/// Stake updates of further contracts remain pending and are summarized in the
+		/// following blocks. The weight of the summary is reserved in `on_initialize`.
+		#[pallet::constant]
+		type MaxStakeSummary: Get<u32>;
+
+		/// Weight information for the extrinsics and chain extensions of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			if T::StakeEventMode::get() == StakeEventMode::PerBlock {
+				<T as Config>::WeightInfo::summarize(T::MaxStakeSummary::get())
+			} else {
+				Weight::zero()
+			}
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			if T::StakeEventMode::get() == StakeEventMode::PerBlock {
+				StakeRequest::<T>::summarize(T::MaxStakeSummary::get());
+			}
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			migration::v1::MoveFromContracts::<T>::on_idle(remaining_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Delegates the stake of `contract_addr` to the validator `delegate_to`.
+		///
+		/// Only the stake owner of a contract that meets the minimum reputation may delegate it.
+		/// The stake score is reset on every change of the delegate.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			contract_addr: T::AccountId,
+			delegate_to: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			<DelegateRequest<T>>::delegate(&origin, &contract_addr, &delegate_to)?;
+			Ok(())
+		}
+
+		/// Hands the stake of `contract_addr` over to `new_owner`.
+		///
+		/// Only the stake owner of a contract that meets the minimum reputation may hand it
+		/// over. The stake score is reset.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_owner())]
+		pub fn update_owner(
+			origin: OriginFor<T>,
+			contract_addr: T::AccountId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			<DelegateRequest<T>>::update_stake_owner(&origin, &contract_addr, &new_owner)?;
+			Ok(())
+		}
+
+		/// Checks whether the signer has enough delegates to validate.
+		///
+		/// Contracts, e.g., staking pools, validate themselves through function 1012 of
+		/// [`crate::chain_ext::UpdateDelegateInfo`].
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::validate())]
+		pub fn validate(origin: OriginFor<T>) -> DispatchResult {
+			let validator = ensure_signed(origin)?;
+			<ValidateRequest<T>>::validate(&validator)?;
+			Ok(())
+		}
+
+		/// Delegates the stake of several contracts, each to its validator.
+		///
+		/// Every delegation is checked like [`Pallet::delegate`]. In
+		/// [`DelegateBatchMode::AllOrNothing`] mode the first rejected delegation fails the whole
+		/// call; in [`DelegateBatchMode::BestEffort`] mode rejected delegations are skipped. The
+		/// result of every delegation is reported by [`Event::DelegatedBatch`].
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::delegate_batch(delegations.len() as u32))]
+		pub fn delegate_batch(
+			origin: OriginFor<T>,
+			delegations: BoundedVec<(T::AccountId, T::AccountId), T::MaxDelegateBatch>,
+			mode: DelegateBatchMode,
+		) -> DispatchResultWithPostInfo {
+			let origin = ensure_signed(origin)?;
+			let results = <DelegateRequest<T>>::delegate_batch(&origin, &delegations, mode)
+				.map_err(|(processed, error)| {
+					error.with_weight(<T as Config>::WeightInfo::delegate_batch(processed))
+				})?;
+			Self::deposit_event(Event::DelegatedBatch { owner: origin, results });
+			Ok(().into())
+		}
+
+		/// Clears the delegation of `contract_addr`, returning it to its owner.
+		///
+		/// Only the stake owner of a delegated contract may undelegate it. The stake score is
+		/// reset and the contract no longer counts towards its validator's delegates.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::undelegate())]
+		pub fn undelegate(origin: OriginFor<T>, contract_addr: T::AccountId) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			<DelegateRequest<T>>::undelegate(&origin, &contract_addr)?;
+			Ok(())
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Stake Score is updated for a contract
+		Staked {
+			/// The contract address for which stake information is updated
+			contract: T::AccountId,
+			/// The contract's associated stake score
+			stake_score: u128,
+		},
+
+		/// Announce a contract meets minimum reputation for staking
+		/// Now it can call [`Pallet::delegate`], update its delegate and stake the contract
+		ReadyToStake {
+			/// The contract address which is ready for staking / delegation
+			contract: T::AccountId,
+		},
+
+		/// Delegate Information is updated for a contract via [`Pallet::delegate`]
+		Delegated {
+			/// The contract address for which delegate information is updated by its owner
+			contract: T::AccountId,
+			/// The contract delegated to which account address i.e., the validator
+			delegate_to: T::AccountId,
+		},
+
+		/// Delegation of a contract is cleared via [`Pallet::undelegate`] or by replacing its code
+		Undelegated {
+			/// The contract address whose delegation is cleared
+			contract: T::AccountId,
+			/// The validator the contract was delegated to
+			delegate_to: T::AccountId,
+		},
+
+		/// Validator validation criteria information as event
+		ValidateInfo {
+			/// The validator's account address i.e., a contract address
+			validator: T::AccountId,
+			/// Number of delegates which the validator can utilize for validation
+			num_delegates: u32,
+			/// Provides Assurance if the validator can start validating
+			can_validate: bool,
+		},
+
+		/// Contracts are delegated via [`Pallet::delegate_batch`]
+		DelegatedBatch {
+			/// The stake owner of the contracts
+			owner: T::AccountId,
+			/// The result of every delegation in the order of the batch
+			results: Vec<DispatchResult>,
+		},
+
+		/// Stake Owner is updated for a contract via [`Pallet::update_owner`]
+		StakeOwner {
+			/// The contract address for which owner information is updated
+			contract: T::AccountId,
+			/// The new stake owner of the contract
+			new_owner: T::AccountId,
+		},
+
+		/// Reputation of a contract is reduced due to a high failure rate
+		ReputationPenalized {
+			/// The contract address whose reputation is reduced
+			contract: T::AccountId,
+			/// Number of executions recorded within the evaluated window
+			calls: u32,
+			/// Number of trapped or reverted executions within the evaluated window
+			failures: u32,
+			/// The contract's reputation after the penalty
+			reputation: u32,
+		},
+
+		/// Stake Information of a contract is adjusted after its code was replaced
+		StakeResetOnCodeChange {
+			/// The contract address whose code was replaced
+			contract: T::AccountId,
+			/// The contract's reputation after the adjustment
+			reputation: u32,
+			/// The contract's stake score after the adjustment
+			stake_score: u128,
+		},
+
+		/// Stake Scores of contracts are updated since the last summary
+		/// Deposited instead of [`Event::Staked`] unless stake events are deposited per frame
+		StakeSummary {
+			/// The contracts whose stake score is updated and their associated stake score
+			contracts: Vec<(T::AccountId, u128)>,
+			/// Number of staked frames across all contracts
+			frames: u32,
+			/// Total gas staked across all contracts
+			gas: u128,
+		},
+
+		/// Constructor gas of a contract is recorded as its deployment bond
+		DeploymentBonded {
+			/// The contract address for which the bond is recorded
+			contract: T::AccountId,
+			/// The gas consumed by the contract's constructor
+			bond: u128,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Stake information for account is not available
+		NoStakeExists,
+		/// The contract or account is already delegated to the same address
+		AlreadyDelegated,
+		/// The contract does not meet the minimum reputation requirement
+		LowReputation,
+		/// Invalid Owner of a contract
+		InvalidContractOwner,
+		/// The contract or account is already owned by the given account address
+		AlreadyOwner,
+		/// The required minimum number of delegates has not been met for validation
+		InsufficientDelegates,
+		/// No validator was found for the given contract address
+		NoValidatorFound,
+		/// The owner links of a contract's stake form a cycle
+		StakeOwnerCycle,
+		/// The owner links of a contract's stake exceed the maximum resolvable depth
+		StakeOwnerDepthExceeded,
+		/// The contract isn't delegated to any validator
+		NotDelegated,
+	}
+
+	/// Tracks Delegate and Stake Score Information of a staked contract
+	///
+	/// Both are kept in a single entry so that a stake update reads the trie only once.
+	#[pallet::storage]
+	#[pallet::getter(fn get_pocs_info)]
+	pub type PocsInfoMap<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, PocsInfo<T>>;
+
+	/// Tracks Number of delegates associated with a validator
+	///
+	/// Gets updated via [`Pallet::delegate`] and [`Pallet::undelegate`] extrinsics.
+	#[pallet::storage]
+	#[pallet::getter(fn get_validator_info)]
+	pub type ValidatorInfoMap<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32>;
+
+	/// Tracks trapped and reverted executions of a contract within a failure window
+	///
+	/// Gets updated outside of the executing frame's storage transaction.
+	#[pallet::storage]
+	pub type FailureInfoMap<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, FailureInfo<T>>;
+
+	/// Tracks Stake Score Information of an uploaded code across its instances and delegate calls
+	///
+	/// Gets removed together with the code.
+	#[pallet::storage]
+	pub type CodeStakeInfoMap<T: Config> = StorageMap<_, Identity, T::Hash, CodeStakeInfo<T>>;
+
+	/// Tracks the deployment bond recorded from a contract's constructor gas
+	///
+	/// Only populated while [`Config::DeploymentBondEnabled`] is set.
+	#[pallet::storage]
+	pub type DeploymentBondMap<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, DeploymentBond<T>>;
+
+	/// Tracks stake updates of contracts which are yet to be announced in a summary
+	///
+	/// Only populated unless [`Config::StakeEventMode`] is per frame.
+	#[pallet::storage]
+	pub type PendingStakeMap<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, PendingStake>;
+
+	/// Tracks the number of contracts added to [`PendingStakeMap`] by the current execution
+	///
+	/// Only populated while [`Config::StakeEventMode`] is per transaction and reset once the
+	/// execution has finished.
+	#[pallet::storage]
+	pub type PendingStakeCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Whether the PoCS storage of the contracts pallet has been moved into this pallet
+	///
+	/// Gets set by [`migration::v1::MoveFromContracts`] once the contracts pallet has none left.
+	#[pallet::storage]
+	pub type ContractsStorageMoved<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Stake and delegate information of contracts
+		///
+		/// Each entry is `(contract, owner, delegate_to, reputation, stake_score)` and must name a
+		/// contract deployed at genesis by the contracts pallet, which has to come first in the
+		/// runtime. A contract without `delegate_to` isn't delegated. The delegate counts of
+		/// validators are derived from these entries.
+		pub stakes: Vec<(T::AccountId, T::AccountId, Option<T::AccountId>, u32, u128)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			let mut seeded = alloc::collections::btree_set::BTreeSet::new();
+			for (contract, owner, delegate_to, reputation, stake_score) in &self.stakes {
+				assert!(
+					pallet_contracts::Pallet::<T>::code_hash(contract).is_some(),
+					"Stake of contract {:?} in genesis names no contract",
+					contract,
+				);
+				assert!(
+					seeded.insert(contract.clone()),
+					"Duplicate stake of contract {:?} in genesis",
+					contract,
+				);
+				<DelegateRequest<T>>::seed(
+					contract,
+					owner,
+					delegate_to.as_ref(),
+					*reputation,
+					*stake_score,
+				);
+			}
+			// A new chain never had PoCS storage in the contracts pallet
+			ContractsStorageMoved::<T>::put(true);
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Checks the consistency of the PoCS storage.
+	///
+	/// See [`stake::do_try_state`].
+	#[cfg(any(feature = "try-runtime", feature = "fuzz", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		stake::do_try_state::<T>()
+	}
+
+	/// Returns the account ultimately controlling the stake of the contract specified by
+	/// `account` ID.
+	///
+	/// See [`DelegateRequest::root_owner`].
+	pub fn stake_owner(account: &T::AccountId) -> Result<T::AccountId, DispatchError> {
+		DelegateRequest::<T>::root_owner(account)
+	}
+}
+
+impl<T: Config> OnContractExecution<T> for Pallet<T> {
+	fn frame_weight(frame: &ExecutedFrame<T>) -> Weight {
+		// `PocsInfoMap` and `PendingStakeMap` or `DeploymentBondMap` of the contract, and the
+		// code owner of unsigned origins from `CodeInfoOf`
+		let mut weight = T::DbWeight::get().reads_writes(3, 2);
+		if frame.entry_point == ExportedFunction::Call {
+			// `CodeStakeInfoMap` and the code owner from `CodeInfoOf`
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+		}
+		if T::StakeEventMode::get() == StakeEventMode::PerTransaction {
+			// `PendingStakeCount` and summarizing the contract once the execution has finished,
+			// since each frame adds at most one contract to the summary
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			weight.saturating_accrue(<T as Config>::WeightInfo::summarize(1));
+		}
+		weight
+	}
+
+	fn on_frame_executed(frame: &ExecutedFrame<T>) -> Result<(), DispatchError> {
+		// Stake of a new contract is owned by its deployer or, if configured, by the origin.
+		// Unsigned origins, e.g. root, fall back to the uploader of the executed code.
+		let account = if T::StakeOwnerIsOrigin::get() { &frame.origin } else { &frame.caller };
+		let stake_owner = match account.account_id() {
+			Ok(account_id) => account_id.clone(),
+			Err(_) => pallet_contracts::Pallet::<T>::code_owner(&frame.code_hash)
+				.ok_or(pallet_contracts::Error::<T>::CodeNotFound)?,
+		};
+		let gas = frame.gas_consumed.ref_time();
+		StakeRequest::<T>::stake(&stake_owner, &frame.contract, &gas, &frame.entry_point)?;
+		// Accrue the same gas to the executed code, including delegate calls
+		if frame.entry_point == ExportedFunction::Call {
+			CodeStakeRequest::<T>::stake(&frame.code_hash, &gas);
+		}
+		Ok(())
+	}
+
+	fn on_call_outcome(contract: &T::AccountId, success: bool) {
+		StakeRequest::<T>::record_outcome(contract, success);
+	}
+
+	fn on_contract_terminated(contract: &T::AccountId) {
+		StakeRequest::<T>::delete(contract);
+	}
+
+	fn on_code_changed(
+		contract: &T::AccountId,
+		_old_code_hash: &T::Hash,
+		_new_code_hash: &T::Hash,
+	) {
+		StakeRequest::<T>::code_changed(contract);
+	}
+
+	fn on_code_removed(code_hash: &T::Hash) {
+		CodeStakeRequest::<T>::delete(code_hash);
+	}
+
+	fn on_execution_finished() {
+		// Only as many contracts are summarized as the frames of the execution paid for
+		if T::StakeEventMode::get() == StakeEventMode::PerTransaction {
+			StakeRequest::<T>::summarize(PendingStakeCount::<T>::take());
+		}
+	}
+}
+
+impl<T: Config> ContractStake<T> for Pallet<T> {
+	fn stake_info(contract: &T::AccountId) -> Result<Vec<u8>, DispatchError> {
+		StakeInfo::<T>::get(contract).map(|stake_info| stake_info.encode())
+	}
+
+	fn delegate_info(contract: &T::AccountId) -> Result<Vec<u8>, DispatchError> {
+		DelegateInfo::<T>::get(contract).map(|delegate_info| delegate_info.encode())
+	}
+
+	fn delegate(
+		owner: &T::AccountId,
+		contract: &T::AccountId,
+		delegate_to: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		DelegateRequest::<T>::delegate(owner, contract, delegate_to)
+	}
+
+	fn frame_stake_score(contract: &T::AccountId, gas: u64) -> Result<u128, DispatchError> {
+		StakeRequest::<T>::frame_stake_score(contract, &gas)
+	}
+
+	fn return_code(error: &DispatchError) -> Option<u32> {
+		PocsReturnCode::from_error::<T>(error).map(Into::into)
+	}
+
+	fn stake_info_weight() -> Weight {
+		<T as Config>::WeightInfo::fetch_stake_info()
+	}
+
+	fn delegate_info_weight() -> Weight {
+		<T as Config>::WeightInfo::fetch_delegate_info()
+	}
+
+	fn delegate_weight() -> Weight {
+		<T as Config>::WeightInfo::delegate()
+	}
+
+	fn delegate_rejected_weight() -> Weight {
+		<T as Config>::WeightInfo::delegate_request_rejected()
+	}
+
+	fn frame_stake_score_weight() -> Weight {
+		<T as Config>::WeightInfo::fetch_frame_stake_score()
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API used to query the stake of contracts.
+	pub trait PocsApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Resolve the account ultimately controlling the stake of a contract.
+		///
+		/// See [`crate::Pallet::stake_owner`].
+		fn stake_owner(address: AccountId) -> Result<AccountId, DispatchError>;
+	}
+}
@@ -0,0 +1,219 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Migrations of the PoCS pallet.
+
+pub mod v1 {
+	//! Move the PoCS storage out of the contracts pallet, where it lived before PoCS became a
+	//! pallet of its own.
+	//!
+	//! The contracts pallet only finishes its multi-block migrations to `PocsInfoMap` in the
+	//! blocks after the runtime upgrade that adds this pallet, while FRAME already stamps the
+	//! in-code storage version of the new pallet during that upgrade. Hence the move isn't keyed
+	//! on the storage version of this pallet but runs from [`Hooks::on_idle`] until
+	//! [`ContractsStorageMoved`] is set.
+	//!
+	//! [`Hooks::on_idle`]: frame_support::traits::Hooks::on_idle
+
+	use crate::{
+		stake::{DelegateInfo, PocsInfo, StakeInfo},
+		CodeStakeInfoMap, Config, ContractsStorageMoved, DeploymentBondMap, FailureInfoMap,
+		PocsInfoMap, ValidatorInfoMap, LOG_TARGET,
+	};
+	use codec::{Decode, Encode};
+	use core::marker::PhantomData;
+	use frame_support::{
+		traits::{Get, GetStorageVersion, StorageVersion},
+		weights::{Weight, WeightMeter},
+	};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// The storage version of the contracts pallet that introduced `PocsInfoMap`.
+	const CONTRACTS_VERSION: u16 = 18;
+
+	/// The `DelegateInfo` stored by the contracts pallet.
+	#[derive(Encode, Decode)]
+	pub struct OldDelegateInfo<T: Config> {
+		pub owner: T::AccountId,
+		pub delegate_to: T::AccountId,
+		pub delegate_at: BlockNumberFor<T>,
+	}
+
+	/// The `PocsInfo` stored by the contracts pallet.
+	#[derive(Encode, Decode)]
+	pub struct OldPocsInfo<T: Config> {
+		pub delegate_info: OldDelegateInfo<T>,
+		pub stake_info: StakeInfo<T>,
+	}
+
+	impl<T: Config> OldPocsInfo<T> {
+		/// Converts the stake to the current layout, clearing a delegation to the owner.
+		pub fn migrate(self) -> PocsInfo<T> {
+			let OldDelegateInfo { owner, delegate_to, delegate_at } = self.delegate_info;
+			let delegate_to = (delegate_to != owner).then_some(delegate_to);
+			let delegate_info = DelegateInfo::<T>::from_parts(owner, delegate_to, delegate_at);
+			PocsInfo::from_parts(delegate_info, self.stake_info)
+		}
+	}
+
+	/// The PoCS storage of the contracts pallet.
+	pub mod old {
+		use super::OldPocsInfo;
+		use crate::{
+			stake::{CodeStakeInfo, DeploymentBond, FailureInfo},
+			Config,
+		};
+		use frame_support::{storage_alias, Identity, Twox64Concat};
+
+		#[storage_alias]
+		pub type PocsInfoMap<T: Config> = StorageMap<
+			pallet_contracts::Pallet<T>,
+			Twox64Concat,
+			<T as frame_system::Config>::AccountId,
+			OldPocsInfo<T>,
+		>;
+
+		#[storage_alias]
+		pub type ValidatorInfoMap<T: Config> = StorageMap<
+			pallet_contracts::Pallet<T>,
+			Twox64Concat,
+			<T as frame_system::Config>::AccountId,
+			u32,
+		>;
+
+		#[storage_alias]
+		pub type FailureInfoMap<T: Config> = StorageMap<
+			pallet_contracts::Pallet<T>,
+			Twox64Concat,
+			<T as frame_system::Config>::AccountId,
+			FailureInfo<T>,
+		>;
+
+		#[storage_alias]
+		pub type CodeStakeInfoMap<T: Config> = StorageMap<
+			pallet_contracts::Pallet<T>,
+			Identity,
+			<T as frame_system::Config>::Hash,
+			CodeStakeInfo<T>,
+		>;
+
+		#[storage_alias]
+		pub type DeploymentBondMap<T: Config> = StorageMap<
+			pallet_contracts::Pallet<T>,
+			Twox64Concat,
+			<T as frame_system::Config>::AccountId,
+			DeploymentBond<T>,
+		>;
+	}
+
+	/// Moves all PoCS storage from the contracts pallet into this pallet, entry by entry.
+	///
+	/// Contracts delegated to their own owner aren't delegated anymore in the layout of this
+	/// pallet. The others are counted towards the validator they are delegated to, while the
+	/// validator counts of the contracts pallet, stored under different rules over time, are
+	/// dropped. Moved entries replace those already present in this pallet, which are created
+	/// afresh, owned by the caller, when a contract is executed before its stake was moved. The
+	/// stake such an entry earned is dropped and its delegation, if any, no longer counts.
+	pub struct MoveFromContracts<T>(PhantomData<T>);
+
+	impl<T: Config> MoveFromContracts<T> {
+		/// Moves as many entries as fit into `limit` and returns the weight consumed.
+		pub fn on_idle(limit: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(limit);
+			let db_weight = T::DbWeight::get();
+			if meter.try_consume(db_weight.reads(2)).is_err() || ContractsStorageMoved::<T>::get() {
+				return meter.consumed()
+			}
+			if pallet_contracts::Pallet::<T>::on_chain_storage_version() <
+				StorageVersion::new(CONTRACTS_VERSION)
+			{
+				return meter.consumed()
+			}
+
+			let moved = drain(
+				old::PocsInfoMap::<T>::drain(),
+				&mut meter,
+				db_weight.reads_writes(4, 4),
+				|(contract, old_pocs_info)| {
+					let replaced = PocsInfoMap::<T>::get(&contract)
+						.and_then(|pocs_info| pocs_info.delegate_info().delegate_to());
+					if let Some(validator) = replaced {
+						ValidatorInfoMap::<T>::mutate_exists(&validator, |count| {
+							*count = count.and_then(|count| count.checked_sub(1)).filter(|c| *c > 0)
+						});
+					}
+					let pocs_info = old_pocs_info.migrate();
+					if let Some(validator) = pocs_info.delegate_info().delegate_to() {
+						ValidatorInfoMap::<T>::mutate(&validator, |count| {
+							*count = Some(count.unwrap_or_default().saturating_add(1))
+						});
+					}
+					PocsInfoMap::<T>::insert(&contract, pocs_info);
+				},
+			) && drain(
+				old::FailureInfoMap::<T>::drain(),
+				&mut meter,
+				db_weight.reads_writes(1, 2),
+				|(contract, failure_info)| FailureInfoMap::<T>::insert(&contract, failure_info),
+			) && drain(
+				old::CodeStakeInfoMap::<T>::drain(),
+				&mut meter,
+				db_weight.reads_writes(1, 2),
+				|(code_hash, code_stake_info)| {
+					CodeStakeInfoMap::<T>::insert(&code_hash, code_stake_info)
+				},
+			) && drain(
+				old::DeploymentBondMap::<T>::drain(),
+				&mut meter,
+				db_weight.reads_writes(1, 2),
+				|(contract, deployment_bond)| {
+					DeploymentBondMap::<T>::insert(&contract, deployment_bond)
+				},
+			) && drain(
+				old::ValidatorInfoMap::<T>::drain(),
+				&mut meter,
+				db_weight.reads_writes(1, 1),
+				|_| (),
+			);
+
+			if moved && meter.try_consume(db_weight.writes(1)).is_ok() {
+				ContractsStorageMoved::<T>::put(true);
+				log::info!(target: LOG_TARGET, "Moved PoCS storage out of the contracts pallet");
+			}
+			meter.consumed()
+		}
+	}
+
+	/// Applies `f` to the items of `iter` as long as `meter` can consume `weight` per item.
+	///
+	/// Returns whether `iter` has been exhausted.
+	fn drain<I: Iterator>(
+		mut iter: I,
+		meter: &mut WeightMeter,
+		weight: Weight,
+		mut f: impl FnMut(I::Item),
+	) -> bool {
+		loop {
+			if meter.try_consume(weight).is_err() {
+				return false
+			}
+			match iter.next() {
+				Some(item) => f(item),
+				None => return true,
+			}
+		}
+	}
+}
@@ -0,0 +1,241 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mock runtime executing contracts with PoCS as their execution hook.
+
+use crate::{
+	self as pallet_pocs,
+	chain_ext::{FetchStakeInfo, UpdateDelegateInfo},
+	stake::StakeEventMode,
+};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::ConstU32,
+	weights::{
+		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
+		Weight,
+	},
+};
+use pallet_contracts::{
+	migration::{v17, v18},
+	Frame, Schedule,
+};
+use sp_runtime::{
+	traits::{Convert, IdentityLookup},
+	AccountId32, BuildStorage, Perbill,
+};
+
+pub use pallet_contracts::test_utils::{ALICE, BOB, CHARLIE, DJANGO, GAS_LIMIT};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		Randomness: pallet_insecure_randomness_collective_flip,
+		Contracts: pallet_contracts,
+		Pocs: pallet_pocs,
+	}
+);
+
+pub mod builder {
+	use super::{RuntimeOrigin, Test};
+	use pallet_contracts::{
+		test_utils::{builder::*, AccountId32, ALICE},
+		Code,
+	};
+	use sp_core::H256;
+
+	pub fn bare_instantiate(code: Code<H256>) -> BareInstantiateBuilder<Test> {
+		BareInstantiateBuilder::<Test>::bare_instantiate(ALICE, code)
+	}
+
+	pub fn bare_call(dest: AccountId32) -> BareCallBuilder<Test> {
+		BareCallBuilder::<Test>::bare_call(ALICE, dest)
+	}
+
+	pub fn call(dest: AccountId32) -> CallBuilder<Test> {
+		CallBuilder::<Test>::call(RuntimeOrigin::signed(ALICE), dest)
+	}
+}
+
+parameter_types! {
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(
+			Weight::from_parts(2u64 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
+		);
+	pub static ExistentialDeposit: u64 = 1;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = AccountId32;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type DbWeight = RocksDbWeight;
+}
+
+impl pallet_insecure_randomness_collective_flip::Config for Test {}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type ExistentialDeposit = ExistentialDeposit;
+	type ReserveIdentifier = [u8; 8];
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig)]
+impl pallet_timestamp::Config for Test {}
+
+parameter_types! {
+	pub MySchedule: Schedule<Test> = <Schedule<Test>>::default();
+	pub const DepositPerByte: u64 = 1;
+	pub const DepositPerItem: u64 = 2;
+	// We need this one set high enough for running benchmarks.
+	pub const DefaultDepositLimit: u64 = 10_000_000;
+}
+
+impl Convert<Weight, u64> for Test {
+	fn convert(w: Weight) -> u64 {
+		w.ref_time()
+	}
+}
+
+#[derive_impl(pallet_contracts::config_preludes::TestDefaultConfig)]
+impl pallet_contracts::Config for Test {
+	type Time = Timestamp;
+	type Randomness = Randomness;
+	type Currency = Balances;
+	type CallStack = [Frame<Self>; 5];
+	type Schedule = MySchedule;
+	type DepositPerByte = DepositPerByte;
+	type DepositPerItem = DepositPerItem;
+	type DefaultDepositLimit = DefaultDepositLimit;
+	type ChainExtension = (FetchStakeInfo<Self>, UpdateDelegateInfo<Self>);
+	type OnContractExecution = Pocs;
+	type ContractStake = Pocs;
+	type Migrations = (v17::Migration<Self>, v18::Migration<Self>);
+}
+
+parameter_types! {
+	pub const FailureWindow: u64 = 10;
+	pub const FailureThreshold: Perbill = Perbill::from_percent(50);
+	pub const CodeChangeRetained: Perbill = Perbill::from_percent(0);
+	pub static DeploymentBondEnabled: bool = false;
+	pub static DeploymentBondDecay: u64 = 0;
+	pub static DeploymentBondMaturity: u128 = 0;
+	pub static StakeOwnerIsOrigin: bool = false;
+	pub static EventMode: StakeEventMode = StakeEventMode::PerFrame;
+	pub static MaxStakeSummary: u32 = 16;
+}
+
+impl pallet_pocs::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type FailureWindow = FailureWindow;
+	type FailureThreshold = FailureThreshold;
+	type FailurePenalty = ConstU32<1>;
+	type CodeChangeReputationRetained = CodeChangeRetained;
+	type CodeChangeStakeRetained = CodeChangeRetained;
+	type DeploymentBondEnabled = DeploymentBondEnabled;
+	type DeploymentBondDecay = DeploymentBondDecay;
+	type DeploymentBondMaturity = DeploymentBondMaturity;
+	type StakeOwnerIsOrigin = StakeOwnerIsOrigin;
+	type StakeEventMode = EventMode;
+	type MaxDelegateBatch = ConstU32<32>;
+	type MaxStakeSummary = MaxStakeSummary;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	existential_deposit: u64,
+	balances: Vec<(AccountId32, u64)>,
+	contracts: Vec<(AccountId32, Vec<u8>, Vec<u8>, Vec<u8>, u128)>,
+	stakes: Vec<(AccountId32, AccountId32, Option<AccountId32>, u32, u128)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			existential_deposit: ExistentialDeposit::get(),
+			balances: vec![],
+			contracts: vec![],
+			stakes: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn existential_deposit(mut self, existential_deposit: u64) -> Self {
+		self.existential_deposit = existential_deposit;
+		self
+	}
+	pub fn with_balances(mut self, balances: Vec<(AccountId32, u64)>) -> Self {
+		self.balances = balances;
+		self
+	}
+	pub fn with_contracts(
+		mut self,
+		contracts: Vec<(AccountId32, Vec<u8>, Vec<u8>, Vec<u8>, u128)>,
+	) -> Self {
+		self.contracts = contracts;
+		self
+	}
+	pub fn with_stakes(
+		mut self,
+		stakes: Vec<(AccountId32, AccountId32, Option<AccountId32>, u32, u128)>,
+	) -> Self {
+		self.stakes = stakes;
+		self
+	}
+	pub fn build(self) -> sp_io::TestExternalities {
+		sp_tracing::try_init_simple();
+		ExistentialDeposit::set(self.existential_deposit);
+		let t = RuntimeGenesisConfig {
+			balances: pallet_balances::GenesisConfig {
+				balances: self.balances,
+				..Default::default()
+			},
+			contracts: pallet_contracts::GenesisConfig { contracts: self.contracts },
+			pocs: pallet_pocs::GenesisConfig { stakes: self.stakes },
+			..Default::default()
+		}
+		.build_storage()
+		.unwrap();
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+
+	/// Builds the externalities and executes `test`, checking the PoCS storage invariants
+	/// afterwards when the `try-runtime` feature is enabled.
+	pub fn build_and_execute<R>(self, test: impl FnOnce() -> R) -> R {
+		self.build().execute_with(|| {
+			let result = test();
+			#[cfg(feature = "try-runtime")]
+			Pocs::do_try_state().unwrap();
+			result
+		})
+	}
+}
+
+pub fn initialize_block(number: u64) {
+	System::reset_events();
+	System::initialize(&number, &[0u8; 32].into(), &Default::default());
+}
@@ -0,0 +1,1411 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// This file is utilized for Proof of Contract Stake Protocol (PoCS).
+//
+
+use crate::{
+	CodeStakeInfoMap, Config, DeploymentBondMap, Error, Event, FailureInfoMap, Pallet as Pocs,
+	PendingStakeCount, PendingStakeMap, PocsInfoMap, ValidatorInfoMap,
+};
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{storage::with_storage_layer, traits::Get};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_contracts::{execution_hook::ExportedFunction, Pallet as Contracts};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Zero, DispatchError, Perbill, RuntimeDebug, Saturating};
+
+/// The minimum reputation required to participate in staking contracts.
+///
+pub(crate) const MIN_REPUTATION: u32 = 3;
+
+/// The minimum number of delegates required for a validator to be eligible.
+///
+pub(crate) const MIN_DELEGATES: u32 = 10;
+
+/// The maximum number of owner links followed when resolving the root owner of a contract.
+///
+/// It also bounds the contracts visited by [`DelegateRequest::root_owner`], hence the linear
+/// `Vec::contains` cycle check compares at most 16 accounts per link.
+///
+const MAX_OWNER_DEPTH: u32 = 16;
+
+/// The fixed unit used for incrementing reputation and initializing it during instantiation.
+///
+pub const REPUTATION_FACTOR: u32 = 1;
+
+/// The initial stake score, set to zero for contract constructor purposes.
+///
+pub const INITIAL_STAKE_SCORE: u128 = 0;
+
+/// Determines how stake score updates of delegated contracts are announced.
+///
+/// - `PerFrame` - An [`Event::Staked`] is deposited for every executed stack frame.
+/// - `PerTransaction` - A single [`Event::StakeSummary`] is deposited once the execution
+///   requested by an origin has finished.
+/// - `PerBlock` - A single [`Event::StakeSummary`] is deposited when the block is finalized.
+///
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen, Default,
+)]
+pub enum StakeEventMode {
+	#[default]
+	PerFrame,
+	PerTransaction,
+	PerBlock,
+}
+
+/// Determines how [`crate::Pallet::delegate_batch`] handles rejected delegations.
+///
+/// - `AllOrNothing` - The first rejected delegation fails the whole batch.
+/// - `BestEffort` - Rejected delegations are skipped, the others are applied.
+///
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	RuntimeDebug,
+	MaxEncodedLen,
+	Default,
+)]
+pub enum DelegateBatchMode {
+	#[default]
+	AllOrNothing,
+	BestEffort,
+}
+
+/// Represents the delegation details of a deployed contract.
+///
+/// It includes:
+/// `owner` - The owner of the contract.
+/// `delegate_to` - The validator account i.e., contract to which the contract is delegated,
+/// `None` while the contract isn't delegated.
+/// `delegate_at` - The block number when the delegation was set.
+///
+#[derive(Encode, Decode, Clone, TypeInfo, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct DelegateInfo<T: Config> {
+	owner: T::AccountId,
+	delegate_to: Option<T::AccountId>,
+	delegate_at: BlockNumberFor<T>,
+}
+
+impl<T: Config> DelegateInfo<T> {
+	/// Returns the owner `AccountId` of the contract associated with this `DelegateInfo`.
+	///
+	pub fn owner(&self) -> T::AccountId {
+		self.owner.clone()
+	}
+
+	/// Returns the `AccountId` of the validator to whom the contract is delegated, if any.
+	///
+	pub fn delegate_to(&self) -> Option<T::AccountId> {
+		self.delegate_to.clone()
+	}
+
+	/// Returns the block number when the delegate information was last updated.
+	///
+	pub fn delegate_at(&self) -> BlockNumberFor<T> {
+		self.delegate_at
+	}
+
+	/// Retrieves the `DelegateInfo` for a given contract address.
+	///
+	pub fn get(contract_addr: &T::AccountId) -> Result<DelegateInfo<T>, DispatchError> {
+		<PocsInfo<T>>::get(contract_addr).map(|pocs_info| pocs_info.delegate_info)
+	}
+
+	/// Returns whether the contract is delegated to a validator.
+	///
+	pub fn is_delegated(&self) -> bool {
+		self.delegate_to.is_some()
+	}
+
+	/// Creates a new `DelegateInfo` instance owned by the deployer, which isn't delegated.
+	///
+	fn new(owner: &T::AccountId) -> Self {
+		Self {
+			owner: owner.clone(),
+			delegate_to: None,
+			delegate_at: frame_system::Pallet::<T>::block_number(),
+		}
+	}
+
+	/// Creates a `DelegateInfo` instance from its fields, e.g., when migrating storage.
+	///
+	pub(crate) fn from_parts(
+		owner: T::AccountId,
+		delegate_to: Option<T::AccountId>,
+		delegate_at: BlockNumberFor<T>,
+	) -> Self {
+		Self { owner, delegate_to, delegate_at }
+	}
+
+	/// Updates the `delegate_to` field and returns an updated `DelegateInfo` instance.
+	///
+	/// Passing `None` clears the delegation.
+	///
+	fn update(&self, delegate: Option<&T::AccountId>) -> Self {
+		Self {
+			owner: self.owner.clone(),
+			delegate_to: delegate.cloned(),
+			delegate_at: frame_system::Pallet::<T>::block_number(),
+		}
+	}
+
+	/// Updates the `owner` field and returns an updated `DelegateInfo` instance
+	///
+	/// The delegation of the contract is kept as is.
+	///
+	pub fn update_owner(&self, new_owner: &T::AccountId) -> Self {
+		Self {
+			owner: new_owner.clone(),
+			delegate_to: self.delegate_to.clone(),
+			delegate_at: frame_system::Pallet::<T>::block_number(),
+		}
+	}
+}
+/// Tracks the gas usage metrics of a contract for staking purposes.
+///
+/// It includes:
+/// `reputation` - The reputation score of the contract.
+/// `blockheight` - The block height of its most recent usage.
+/// `stake_score` - The stake score associated with the contract.
+///
+#[derive(Encode, Decode, Clone, TypeInfo, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct StakeInfo<T: Config> {
+	reputation: u32,
+	blockheight: BlockNumberFor<T>,
+	stake_score: u128,
+}
+
+impl<T: Config> StakeInfo<T> {
+	/// Returns the stake score of a contract's `StakeInfo`.
+	///
+	pub fn stake_score(&self) -> u128 {
+		self.stake_score
+	}
+
+	/// Returns the reputation score of a contract's `StakeInfo`.
+	///
+	pub fn reputation(&self) -> u32 {
+		self.reputation
+	}
+
+	/// Returns the block height of the most recent interaction with the contract.
+	///
+	pub fn blockheight(&self) -> BlockNumberFor<T> {
+		self.blockheight
+	}
+
+	/// Retrieves the `StakeInfo` of an instantiated contract.
+	///
+	pub fn get(contract_addr: &T::AccountId) -> Result<StakeInfo<T>, DispatchError> {
+		<PocsInfo<T>>::get(contract_addr).map(|pocs_info| pocs_info.stake_info)
+	}
+
+	/// Creates a new `StakeInfo` instance using predefined constants for instantiation.
+	///
+	fn new() -> Self {
+		Self {
+			reputation: REPUTATION_FACTOR,
+			blockheight: <frame_system::Pallet<T>>::block_number(),
+			stake_score: INITIAL_STAKE_SCORE,
+		}
+	}
+
+	/// Returns the stake score earned by the gas provided at the current reputation.
+	///
+	/// The gas is weighted by the reputation for the first interaction within a block only.
+	///
+	pub fn earned(&self, gas: &u64) -> u128 {
+		earned::<T>(self.reputation, self.blockheight, gas)
+	}
+
+	/// Updates the stake score based on gas usage provided and adjusts reputation if the block height has changed.
+	///
+	fn update(&self, gas: &u64) -> Self {
+		let (reputation, blockheight, stake_score) =
+			accrue::<T>(self.reputation, self.blockheight, self.stake_score, gas);
+		Self { reputation, blockheight, stake_score }
+	}
+
+	/// Resets the stake score in `StakeInfo` to zero, updates the block number, and retains the reputation.
+	///
+	fn reset(&self) -> Self {
+		Self {
+			reputation: self.reputation,
+			blockheight: <frame_system::Pallet<T>>::block_number(),
+			stake_score: INITIAL_STAKE_SCORE,
+		}
+	}
+
+	/// Retains the configured share of reputation and stake score after a code replacement.
+	///
+	/// The reputation never drops below [`REPUTATION_FACTOR`] and the block height is updated.
+	///
+	fn adjust_on_code_change(&self) -> Self {
+		Self {
+			reputation: T::CodeChangeReputationRetained::get()
+				.mul_floor(self.reputation)
+				.max(REPUTATION_FACTOR),
+			blockheight: <frame_system::Pallet<T>>::block_number(),
+			stake_score: T::CodeChangeStakeRetained::get().mul_floor(self.stake_score),
+		}
+	}
+
+	/// Deducts [`Config::FailurePenalty`] from the reputation, never going below [`REPUTATION_FACTOR`].
+	///
+	fn penalize(&self) -> Self {
+		Self {
+			reputation: self
+				.reputation
+				.saturating_sub(T::FailurePenalty::get())
+				.max(REPUTATION_FACTOR),
+			blockheight: self.blockheight,
+			stake_score: self.stake_score,
+		}
+	}
+}
+
+/// Returns the stake score earned by the gas provided at the given reputation.
+///
+/// The gas is weighted by the reputation for the first interaction within a block only, i.e.,
+/// if the current block is past `blockheight`.
+///
+fn earned<T: Config>(reputation: u32, blockheight: BlockNumberFor<T>, gas: &u64) -> u128 {
+	let gas_cast = *gas as u128;
+	if <frame_system::Pallet<T>>::block_number() > blockheight {
+		gas_cast.saturating_mul(reputation.into())
+	} else {
+		gas_cast
+	}
+}
+
+/// Accrues the gas provided to a stake score, shared by [`StakeInfo`] and [`CodeStakeInfo`].
+///
+/// The first interaction within a block raises the reputation by [`REPUTATION_FACTOR`].
+/// Returns the new reputation, block height and stake score.
+///
+fn accrue<T: Config>(
+	reputation: u32,
+	blockheight: BlockNumberFor<T>,
+	stake_score: u128,
+	gas: &u64,
+) -> (u32, BlockNumberFor<T>, u128) {
+	let current_block_height = <frame_system::Pallet<T>>::block_number();
+	let new_stake_score = earned::<T>(reputation, blockheight, gas).saturating_add(stake_score);
+	if current_block_height > blockheight {
+		(reputation.saturating_add(REPUTATION_FACTOR), current_block_height, new_stake_score)
+	} else {
+		(reputation, current_block_height, new_stake_score)
+	}
+}
+
+/// Combines the delegate and stake information of a contract into a single storage entry.
+///
+/// It includes:
+/// `delegate_info` - The delegation details of the contract.
+/// `stake_info` - The gas usage metrics of the contract.
+///
+#[derive(Encode, Decode, Clone, TypeInfo, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct PocsInfo<T: Config> {
+	delegate_info: DelegateInfo<T>,
+	stake_info: StakeInfo<T>,
+}
+
+impl<T: Config> PocsInfo<T> {
+	/// Returns the `DelegateInfo` of the contract.
+	///
+	pub fn delegate_info(&self) -> DelegateInfo<T> {
+		self.delegate_info.clone()
+	}
+
+	/// Returns the `StakeInfo` of the contract.
+	///
+	pub fn stake_info(&self) -> StakeInfo<T> {
+		self.stake_info.clone()
+	}
+
+	/// Retrieves the `PocsInfo` of an instantiated contract.
+	///
+	pub fn get(contract_addr: &T::AccountId) -> Result<PocsInfo<T>, DispatchError> {
+		Pocs::<T>::get_pocs_info(contract_addr).ok_or_else(|| Error::<T>::NoStakeExists.into())
+	}
+
+	/// Creates a new `PocsInfo` instance owned by the deployer, which isn't delegated.
+	///
+	fn new(owner: &T::AccountId) -> Self {
+		Self { delegate_info: <DelegateInfo<T>>::new(owner), stake_info: <StakeInfo<T>>::new() }
+	}
+
+	/// Creates a `PocsInfo` instance from its fields, e.g., when migrating storage.
+	///
+	pub(crate) fn from_parts(delegate_info: DelegateInfo<T>, stake_info: StakeInfo<T>) -> Self {
+		Self { delegate_info, stake_info }
+	}
+
+	/// Replaces the `StakeInfo` of a contract while keeping its `DelegateInfo`.
+	///
+	fn set_stake_info(contract_addr: &T::AccountId, stake_info: &StakeInfo<T>) {
+		PocsInfoMap::<T>::mutate(contract_addr, |pocs_info| {
+			if let Some(pocs_info) = pocs_info {
+				pocs_info.stake_info = stake_info.clone();
+			}
+		});
+	}
+
+	/// Replaces the `DelegateInfo` of a contract while keeping its `StakeInfo`.
+	///
+	fn set_delegate_info(contract_addr: &T::AccountId, delegate_info: &DelegateInfo<T>) {
+		PocsInfoMap::<T>::mutate(contract_addr, |pocs_info| {
+			if let Some(pocs_info) = pocs_info {
+				pocs_info.delegate_info = delegate_info.clone();
+			}
+		});
+	}
+}
+
+/// Tracks the gas usage metrics of an uploaded code across all of its executions.
+///
+/// It includes:
+/// `owner` - The uploader of the code, derived from its `CodeInfo`.
+/// `reputation` - The reputation score of the code.
+/// `blockheight` - The block height of its most recent usage.
+/// `stake_score` - The stake score accrued by instances of and delegate calls into the code.
+///
+#[derive(Encode, Decode, Clone, TypeInfo, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct CodeStakeInfo<T: Config> {
+	owner: T::AccountId,
+	reputation: u32,
+	blockheight: BlockNumberFor<T>,
+	stake_score: u128,
+}
+
+impl<T: Config> CodeStakeInfo<T> {
+	/// Returns the owner `AccountId` of the code associated with this `CodeStakeInfo`.
+	///
+	pub fn owner(&self) -> T::AccountId {
+		self.owner.clone()
+	}
+
+	/// Returns the reputation score of the code.
+	///
+	pub fn reputation(&self) -> u32 {
+		self.reputation
+	}
+
+	/// Returns the block height of the most recent execution of the code.
+	///
+	pub fn blockheight(&self) -> BlockNumberFor<T> {
+		self.blockheight
+	}
+
+	/// Returns the stake score accrued by the code.
+	///
+	pub fn stake_score(&self) -> u128 {
+		self.stake_score
+	}
+
+	/// Retrieves the `CodeStakeInfo` of an uploaded code.
+	///
+	pub fn get(code_hash: &T::Hash) -> Result<CodeStakeInfo<T>, DispatchError> {
+		CodeStakeInfoMap::<T>::get(code_hash).ok_or_else(|| Error::<T>::NoStakeExists.into())
+	}
+
+	/// Creates a new `CodeStakeInfo` instance owned by the given code uploader.
+	///
+	fn new(owner: &T::AccountId) -> Self {
+		Self {
+			owner: owner.clone(),
+			reputation: REPUTATION_FACTOR,
+			blockheight: <frame_system::Pallet<T>>::block_number(),
+			stake_score: INITIAL_STAKE_SCORE,
+		}
+	}
+
+	/// Updates the stake score based on gas usage provided and adjusts reputation if the block height has changed.
+	///
+	fn update(&self, gas: &u64) -> Self {
+		let (reputation, blockheight, stake_score) =
+			accrue::<T>(self.reputation, self.blockheight, self.stake_score, gas);
+		Self { owner: self.owner.clone(), reputation, blockheight, stake_score }
+	}
+}
+
+/// Represents the deployment bond of a contract recorded from its constructor gas.
+///
+/// It includes:
+/// `bond` - The gas consumed by the contract's constructor.
+/// `bonded_at` - The block number at which the contract was instantiated.
+///
+#[derive(Encode, Decode, Clone, TypeInfo, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct DeploymentBond<T: Config> {
+	bond: u128,
+	bonded_at: BlockNumberFor<T>,
+}
+
+impl<T: Config> DeploymentBond<T> {
+	/// Returns the bond recorded at instantiation.
+	///
+	pub fn bond(&self) -> u128 {
+		self.bond
+	}
+
+	/// Returns the block number at which the bond was recorded.
+	///
+	pub fn bonded_at(&self) -> BlockNumberFor<T> {
+		self.bonded_at
+	}
+
+	/// Retrieves the `DeploymentBond` of a contract.
+	///
+	pub fn get(contract_addr: &T::AccountId) -> Result<DeploymentBond<T>, DispatchError> {
+		DeploymentBondMap::<T>::get(contract_addr).ok_or_else(|| Error::<T>::NoStakeExists.into())
+	}
+
+	/// Creates a new `DeploymentBond` from the gas consumed by a constructor.
+	///
+	fn new(gas: &u64) -> Self {
+		Self { bond: *gas as u128, bonded_at: <frame_system::Pallet<T>>::block_number() }
+	}
+
+	/// Returns the bond remaining after decay over [`Config::DeploymentBondDecay`] blocks.
+	///
+	pub fn effective_bond(&self) -> u128 {
+		let decay = T::DeploymentBondDecay::get();
+		if decay.is_zero() {
+			return self.bond
+		}
+		let elapsed = <frame_system::Pallet<T>>::block_number().saturating_sub(self.bonded_at);
+		if elapsed >= decay {
+			return 0
+		}
+		Perbill::from_rational(decay.saturating_sub(elapsed), decay).mul_floor(self.bond)
+	}
+
+	/// Checks whether the effective bond satisfies [`Config::DeploymentBondMaturity`].
+	///
+	fn matured(&self) -> bool {
+		let maturity = T::DeploymentBondMaturity::get();
+		!maturity.is_zero() && self.effective_bond() >= maturity
+	}
+}
+
+/// Tracks the execution outcomes of a contract within the current failure window.
+///
+/// It includes:
+/// `window_start` - The block number at which the current window was opened.
+/// `calls` - The number of executions recorded within the window.
+/// `failures` - The number of trapped or reverted executions within the window.
+///
+#[derive(Encode, Decode, Clone, TypeInfo, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct FailureInfo<T: Config> {
+	window_start: BlockNumberFor<T>,
+	calls: u32,
+	failures: u32,
+}
+
+impl<T: Config> FailureInfo<T> {
+	/// Returns the block number at which the current failure window was opened.
+	///
+	pub fn window_start(&self) -> BlockNumberFor<T> {
+		self.window_start
+	}
+
+	/// Returns the number of executions recorded within the current window.
+	///
+	pub fn calls(&self) -> u32 {
+		self.calls
+	}
+
+	/// Returns the number of failed executions recorded within the current window.
+	///
+	pub fn failures(&self) -> u32 {
+		self.failures
+	}
+
+	/// Retrieves the `FailureInfo` of a contract.
+	///
+	pub fn get(contract_addr: &T::AccountId) -> Result<FailureInfo<T>, DispatchError> {
+		FailureInfoMap::<T>::get(contract_addr).ok_or_else(|| Error::<T>::NoStakeExists.into())
+	}
+
+	/// Creates an empty `FailureInfo` whose window opens at the current block.
+	///
+	fn new() -> Self {
+		Self { window_start: <frame_system::Pallet<T>>::block_number(), calls: 0, failures: 0 }
+	}
+
+	/// Returns whether the window has spanned at least [`Config::FailureWindow`] blocks.
+	///
+	fn window_elapsed(&self) -> bool {
+		let current_block_height = <frame_system::Pallet<T>>::block_number();
+		current_block_height >= self.window_start.saturating_add(T::FailureWindow::get())
+	}
+
+	/// Returns whether the failure rate of the window is above [`Config::FailureThreshold`].
+	///
+	fn exceeds_threshold(&self) -> bool {
+		self.failures > 0 &&
+			Perbill::from_rational(self.failures, self.calls) > T::FailureThreshold::get()
+	}
+
+	/// Records a single execution outcome and returns an updated `FailureInfo` instance.
+	///
+	fn record(&self, success: bool) -> Self {
+		Self {
+			window_start: self.window_start,
+			calls: self.calls.saturating_add(1),
+			failures: if success { self.failures } else { self.failures.saturating_add(1) },
+		}
+	}
+}
+
+/// Accumulates the stake updates of a contract that haven't been announced yet.
+///
+/// It includes:
+/// `frames` - The number of staked frames since the last announcement.
+/// `gas` - The gas staked by these frames.
+///
+#[derive(Encode, Decode, Clone, Default, TypeInfo, RuntimeDebug, PartialEq, Eq, MaxEncodedLen)]
+pub struct PendingStake {
+	frames: u32,
+	gas: u128,
+}
+
+impl PendingStake {
+	/// Returns the number of staked frames since the last announcement.
+	///
+	pub fn frames(&self) -> u32 {
+		self.frames
+	}
+
+	/// Returns the gas staked since the last announcement.
+	///
+	pub fn gas(&self) -> u128 {
+		self.gas
+	}
+
+	/// Adds the gas staked by a single frame.
+	///
+	pub(crate) fn add(&self, gas: &u64) -> Self {
+		Self { frames: self.frames.saturating_add(1), gas: self.gas.saturating_add(*gas as u128) }
+	}
+}
+
+/// Represents a stake request for each contract invocation or execution.
+///
+/// It includes
+/// - `contract` - The account ID of the contract being invoked.
+/// - `caller` - The account ID of the caller (transaction origin or another contract).
+/// - `gas` - The total gas expenditure for this invocation of a single stack frame.
+///
+#[derive(Encode, Decode, Clone, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct StakeRequest<T: Config> {
+	contract: T::AccountId,
+	caller: T::AccountId,
+	gas: u64,
+}
+
+impl<T: Config> StakeRequest<T> {
+	/// Processes a stake request for a given contract.
+	///
+	/// This function serves as the entry point for handling stake requests.  
+	/// It first checks whether stake information already exists for the specified contract.  
+	/// If it does, a new stake request is processed; otherwise, an empty stake info entry is created.
+	/// Only an `entry_point` of [`ExportedFunction::Constructor`] records a deployment bond, as
+	/// the first execution of an existing contract without stake is a call.
+	///
+	pub fn stake(
+		origin: &T::AccountId,
+		contract_addr: &T::AccountId,
+		gas: &u64,
+		entry_point: &ExportedFunction,
+	) -> Result<(), DispatchError> {
+		if let Some(pocs_info) = PocsInfoMap::<T>::get(contract_addr) {
+			Self::new(contract_addr, pocs_info, gas);
+		} else {
+			Self::empty(origin, contract_addr);
+			if *entry_point == ExportedFunction::Constructor {
+				Self::bond(contract_addr, gas);
+			}
+		}
+		Ok(())
+	}
+
+	/// Returns the stake score a frame of the contract consuming `gas` would earn.
+	///
+	/// Mirrors [`Self::stake`] for a contract with stake: contracts which aren't delegated
+	/// earn nothing, others earn [`StakeInfo::earned`] at their current reputation.
+	///
+	pub fn frame_stake_score(
+		contract_addr: &T::AccountId,
+		gas: &u64,
+	) -> Result<u128, DispatchError> {
+		let PocsInfo { delegate_info, stake_info } = <PocsInfo<T>>::get(contract_addr)?;
+		if !delegate_info.is_delegated() {
+			return Ok(0)
+		}
+		Ok(stake_info.earned(gas))
+	}
+
+	/// Initializes an empty stake and delegate entry for a contract.
+	///
+	/// This helper function creates a new `PocsInfo` entry holding both the
+	/// `StakeInfo` and `DelegateInfo` of the specified contract.
+	///
+	fn empty(origin: &T::AccountId, contract_addr: &T::AccountId) {
+		PocsInfoMap::<T>::insert(contract_addr, <PocsInfo<T>>::new(origin));
+	}
+
+	/// Records the constructor gas of a new contract as its deployment bond.
+	///
+	/// Only applies if [`Config::DeploymentBondEnabled`] is set. The bond does not add to the
+	/// stake score; it only decays and may count towards maturity for delegation.
+	///
+	fn bond(contract_addr: &T::AccountId, gas: &u64) {
+		if !T::DeploymentBondEnabled::get() {
+			return
+		}
+		let deployment_bond = <DeploymentBond<T>>::new(gas);
+		DeploymentBondMap::<T>::insert(contract_addr, deployment_bond.clone());
+		Pocs::<T>::deposit_event(Event::DeploymentBonded {
+			contract: contract_addr.clone(),
+			bond: deployment_bond.bond,
+		});
+		if deployment_bond.matured() {
+			Pocs::<T>::deposit_event(Event::ReadyToStake { contract: contract_addr.clone() });
+		}
+	}
+
+	/// Processes new stake request and updates stake info for the given contract.
+	///
+	/// This helper function takes the existing delegate and stake information  
+	/// of the specified contract and updates the stake score based on  
+	/// delegation conditions. It also triggers appropriate events  
+	/// and decides whether bonding actions are necessary.
+	///
+	fn new(contract_addr: &T::AccountId, pocs_info: PocsInfo<T>, gas: &u64) {
+		let PocsInfo { delegate_info, stake_info } = pocs_info;
+
+		// Provide zero gas if contract isn't matured i.e., haven't delegated at all.
+		let gas = if delegate_info.is_delegated() { gas } else { &0 };
+
+		let new_stake_info = <StakeInfo<T>>::update(&stake_info, gas);
+		PocsInfoMap::<T>::insert(
+			contract_addr,
+			PocsInfo { delegate_info: delegate_info.clone(), stake_info: new_stake_info.clone() },
+		);
+
+		// No Stake Update due to zero gas, hence no stake event emission
+		if delegate_info.is_delegated() {
+			Self::announce(contract_addr, &new_stake_info, gas);
+		}
+
+		// If contract passes criteria notify ready for staking!
+		if new_stake_info.reputation == MIN_REPUTATION {
+			Pocs::<T>::deposit_event(Event::ReadyToStake { contract: contract_addr.clone() });
+		}
+	}
+
+	/// Announces an updated stake score according to [`Config::StakeEventMode`].
+	///
+	/// Unless stake events are deposited per frame, the update is accumulated in
+	/// [`PendingStakeMap`] until [`Self::summarize`] is called. Per transaction, contracts newly
+	/// added are counted in [`PendingStakeCount`] to bound the summary of the execution.
+	///
+	fn announce(contract_addr: &T::AccountId, stake_info: &StakeInfo<T>, gas: &u64) {
+		match T::StakeEventMode::get() {
+			StakeEventMode::PerFrame => Pocs::<T>::deposit_event(Event::Staked {
+				contract: contract_addr.clone(),
+				stake_score: stake_info.stake_score,
+			}),
+			StakeEventMode::PerTransaction => {
+				PendingStakeMap::<T>::mutate(contract_addr, |pending| {
+					if pending.is_none() {
+						PendingStakeCount::<T>::mutate(|count| *count = count.saturating_add(1));
+					}
+					*pending = Some(pending.clone().unwrap_or_default().add(gas));
+				});
+			},
+			StakeEventMode::PerBlock => {
+				PendingStakeMap::<T>::mutate(contract_addr, |pending| {
+					*pending = Some(pending.clone().unwrap_or_default().add(gas));
+				});
+			},
+		}
+	}
+
+	/// Deposits a single [`Event::StakeSummary`] for the stake updates of at most `limit`
+	/// contracts accumulated in [`PendingStakeMap`] and removes them.
+	///
+	/// Updates of further contracts remain pending for the next summary. No event is deposited
+	/// if nothing was staked since the last summary.
+	///
+	pub fn summarize(limit: u32) {
+		let mut contracts = Vec::new();
+		let mut frames: u32 = 0;
+		let mut gas: u128 = 0;
+		for (contract_addr, pending) in PendingStakeMap::<T>::drain().take(limit as usize) {
+			frames = frames.saturating_add(pending.frames);
+			gas = gas.saturating_add(pending.gas);
+			if let Ok(stake_info) = <StakeInfo<T>>::get(&contract_addr) {
+				contracts.push((contract_addr, stake_info.stake_score));
+			}
+		}
+		if contracts.is_empty() {
+			return
+		}
+		Pocs::<T>::deposit_event(Event::StakeSummary { contracts, frames, gas });
+	}
+
+	/// Records the outcome of a contract execution for failure rate tracking.
+	///
+	/// Called after the frame's storage transaction has been committed or rolled back, so
+	/// that trapped and reverted executions are counted as well. Once a window of
+	/// [`Config::FailureWindow`] blocks has elapsed, its failure rate is compared against
+	/// [`Config::FailureThreshold`] and the contract's reputation is reduced by
+	/// [`Config::FailurePenalty`] if it is exceeded. A new window is opened afterwards.
+	///
+	/// Contracts without stake information are ignored.
+	///
+	pub fn record_outcome(contract_addr: &T::AccountId, success: bool) {
+		if !PocsInfoMap::<T>::contains_key(contract_addr) {
+			return
+		}
+		let failure_info = match FailureInfoMap::<T>::get(contract_addr) {
+			Some(failure_info) if failure_info.window_elapsed() => {
+				if failure_info.exceeds_threshold() {
+					Self::penalize(contract_addr, &failure_info);
+				}
+				<FailureInfo<T>>::new()
+			},
+			Some(failure_info) => failure_info,
+			None => <FailureInfo<T>>::new(),
+		};
+		FailureInfoMap::<T>::insert(contract_addr, failure_info.record(success));
+	}
+
+	/// Applies the failure penalty to the reputation of the given contract.
+	///
+	fn penalize(contract_addr: &T::AccountId, failure_info: &FailureInfo<T>) {
+		if let Ok(stake_info) = <StakeInfo<T>>::get(contract_addr) {
+			let new_stake_info = <StakeInfo<T>>::penalize(&stake_info);
+			<PocsInfo<T>>::set_stake_info(contract_addr, &new_stake_info);
+			Pocs::<T>::deposit_event(Event::ReputationPenalized {
+				contract: contract_addr.clone(),
+				calls: failure_info.calls,
+				failures: failure_info.failures,
+				reputation: new_stake_info.reputation,
+			});
+		}
+	}
+
+	/// Adjusts the stake information of a contract whose code has been replaced.
+	///
+	/// Reputation earned with the previous code must not carry over to entirely different
+	/// logic. Hence only the share configured by [`Config::CodeChangeReputationRetained`] and
+	/// [`Config::CodeChangeStakeRetained`] is kept, and the deployment bond is dropped.
+	/// A delegation is cleared as well, since the new code has to reach [`MIN_REPUTATION`]
+	/// before its owner may delegate it again. Contracts without stake are ignored.
+	///
+	pub fn code_changed(contract_addr: &T::AccountId) {
+		if let Ok(stake_info) = <StakeInfo<T>>::get(contract_addr) {
+			DeploymentBondMap::<T>::remove(contract_addr);
+			let new_stake_info = <StakeInfo<T>>::adjust_on_code_change(&stake_info);
+			<PocsInfo<T>>::set_stake_info(contract_addr, &new_stake_info);
+			Pocs::<T>::deposit_event(Event::StakeResetOnCodeChange {
+				contract: contract_addr.clone(),
+				reputation: new_stake_info.reputation,
+				stake_score: new_stake_info.stake_score,
+			});
+			if let Ok(delegate_info) = <DelegateInfo<T>>::get(contract_addr) {
+				if let Some(delegate_to) = delegate_info.delegate_to.clone() {
+					let new_delegate_info = <DelegateInfo<T>>::update(&delegate_info, None);
+					<PocsInfo<T>>::set_delegate_info(contract_addr, &new_delegate_info);
+					Pocs::<T>::deposit_event(Event::Undelegated {
+						contract: contract_addr.clone(),
+						delegate_to: delegate_to.clone(),
+					});
+					<DelegateRequest<T>>::decrement(&delegate_to);
+				}
+			}
+		}
+	}
+
+	/// Deletes stake and delegate information for a given contract.
+	///
+	/// This function removes the `PocsInfo` record associated with  
+	/// the specified contract address. If the contract was delegated,  
+	/// it decrements the [`Pallet::ValidateInfo`] Map num_delegates count.
+	///
+	pub fn delete(contract_addr: &T::AccountId) {
+		PendingStakeMap::<T>::remove(&contract_addr);
+		FailureInfoMap::<T>::remove(&contract_addr);
+		DeploymentBondMap::<T>::remove(&contract_addr);
+		if let Some(pocs_info) = PocsInfoMap::<T>::take(&contract_addr) {
+			if let Some(delegate_to) = pocs_info.delegate_info.delegate_to {
+				<DelegateRequest<T>>::decrement(&delegate_to);
+			}
+		}
+	}
+}
+
+/// Represents a stake request for the code executed by a stack frame.
+///
+/// It includes
+/// - `code_hash` - The hash of the code being executed, either by an instance or a delegate call.
+/// - `gas` - The total gas expenditure for this execution of a single stack frame.
+///
+#[derive(Encode, Decode, Clone, PartialEq, TypeInfo, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct CodeStakeRequest<T: Config> {
+	code_hash: T::Hash,
+	gas: u64,
+}
+
+impl<T: Config> CodeStakeRequest<T> {
+	/// Accrues the gas of a stack frame to the stake of the executed code.
+	///
+	/// Library code reached through delegate calls credits its uploader instead of only the
+	/// calling contract. An entry owned by the uploader recorded in `CodeInfo` is created on
+	/// first execution. Codes without `CodeInfo` are ignored.
+	///
+	pub fn stake(code_hash: &T::Hash, gas: &u64) {
+		let code_stake_info = match CodeStakeInfoMap::<T>::get(code_hash) {
+			Some(code_stake_info) => code_stake_info,
+			None => match Contracts::<T>::code_owner(code_hash) {
+				Some(owner) => <CodeStakeInfo<T>>::new(&owner),
+				None => return,
+			},
+		};
+		CodeStakeInfoMap::<T>::insert(code_hash, <CodeStakeInfo<T>>::update(&code_stake_info, gas));
+	}
+
+	/// Deletes the stake information of a removed code.
+	///
+	pub fn delete(code_hash: &T::Hash) {
+		CodeStakeInfoMap::<T>::remove(code_hash);
+	}
+}
+
+/// Represents a delegate request for a contract.
+///
+/// It includes:
+/// - `contract` - The contract for which the delegation request is made.
+/// - `delegate_to` - The account to which the contract is delegating to.
+///
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct DelegateRequest<T: Config> {
+	contract: T::AccountId,
+	delegate_to: T::AccountId,
+}
+
+impl<T: Config> DelegateRequest<T> {
+	/// Delegates several contracts, each to its own delegate account.
+	///
+	/// Every delegation is processed by [`Self::delegate`] within its own storage layer and
+	/// returns its result in order. In [`DelegateBatchMode::AllOrNothing`] mode the first
+	/// rejected delegation stops the batch, returning the number of processed delegations
+	/// and the error; the caller is responsible for reverting the delegations applied before.
+	///
+	pub fn delegate_batch(
+		origin: &T::AccountId,
+		delegations: &[(T::AccountId, T::AccountId)],
+		mode: DelegateBatchMode,
+	) -> Result<Vec<Result<(), DispatchError>>, (u32, DispatchError)> {
+		let mut results = Vec::with_capacity(delegations.len());
+		for (index, (contract_addr, delegate_to)) in delegations.iter().enumerate() {
+			let result = with_storage_layer(|| Self::delegate(origin, contract_addr, delegate_to));
+			if let Err(e) = result {
+				if mode == DelegateBatchMode::AllOrNothing {
+					return Err((index as u32 + 1, e))
+				}
+			}
+			results.push(result);
+		}
+		Ok(results)
+	}
+
+	/// Delegates a contract to a new delegate account.
+	///
+	/// This function checks if the contract has an existing stake, verifies the  
+	/// caller's ownership, and ensures the contract meets the minimum reputation  
+	/// requirements. If the new delegate is different from the existing one,  
+	/// it resets the stake, updates the delegate information, and emits a  
+	/// `Delegated` event. If the delegate remains the same, an error is returned.   
+	///
+	pub fn delegate(
+		origin: &T::AccountId,
+		contract_addr: &T::AccountId,
+		delegate_to: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		Self::stake_exists(contract_addr)?;
+		let delegate_info = Self::owner_check(origin, contract_addr)?;
+		let stake_info = <DelegateRequest<T>>::min_reputation(&contract_addr)?;
+		if delegate_info.delegate_to.as_ref() != Some(delegate_to) {
+			let new_delegate_info = <DelegateInfo<T>>::update(&delegate_info, Some(delegate_to));
+			Self::reset_stake(contract_addr, &new_delegate_info, &stake_info);
+			Pocs::<T>::deposit_event(Event::Delegated {
+				contract: contract_addr.clone(),
+				delegate_to: delegate_to.clone(),
+			});
+			if let Some(old_delegate_to) = &delegate_info.delegate_to {
+				Self::decrement(old_delegate_to);
+			}
+			Self::increment(delegate_to);
+			Ok(())
+		} else {
+			return Err(Error::<T>::AlreadyDelegated.into())
+		}
+	}
+
+	/// Clears the delegation of a contract, returning it to its owner.
+	///
+	/// This function checks if the contract has an existing stake and verifies the  
+	/// caller's ownership. Unlike delegating, no minimum reputation is required.  
+	/// The stake is reset, the validator's delegate count decremented and an  
+	/// `Undelegated` event emitted. If the contract isn't delegated, an error is returned.
+	///
+	pub fn undelegate(
+		origin: &T::AccountId,
+		contract_addr: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		Self::stake_exists(contract_addr)?;
+		let delegate_info = Self::owner_check(origin, contract_addr)?;
+		let Some(delegate_to) = delegate_info.delegate_to.clone() else {
+			return Err(Error::<T>::NotDelegated.into())
+		};
+		let stake_info = <StakeInfo<T>>::get(contract_addr)?;
+		let new_delegate_info = <DelegateInfo<T>>::update(&delegate_info, None);
+		Self::reset_stake(contract_addr, &new_delegate_info, &stake_info);
+		Pocs::<T>::deposit_event(Event::Undelegated {
+			contract: contract_addr.clone(),
+			delegate_to: delegate_to.clone(),
+		});
+		Self::decrement(&delegate_to);
+		Ok(())
+	}
+
+	/// Checks if the contract has an existing stake.
+	///
+	fn stake_exists(contract_addr: &T::AccountId) -> Result<(), DispatchError> {
+		if PocsInfoMap::<T>::contains_key(contract_addr) {
+			Ok(())
+		} else {
+			Err(Error::<T>::NoStakeExists.into())
+		}
+	}
+
+	/// Ensures the given account is the owner of the contract.
+	///
+	fn owner_check(
+		owner: &T::AccountId,
+		contract_addr: &T::AccountId,
+	) -> Result<DelegateInfo<T>, DispatchError> {
+		let delegate_info = <DelegateInfo<T>>::get(contract_addr)?;
+		if delegate_info.owner == *owner {
+			Ok(delegate_info)
+		} else {
+			Err(Error::<T>::InvalidContractOwner.into())
+		}
+	}
+
+	/// Checks if the contract meets the minimum reputation requirement.
+	///
+	/// A contract whose deployment bond has matured is exempt from the requirement.
+	///
+	fn min_reputation(contract_addr: &T::AccountId) -> Result<StakeInfo<T>, DispatchError> {
+		let stake_info = <StakeInfo<T>>::get(contract_addr)?;
+		let bond_matured = <DeploymentBond<T>>::get(contract_addr)
+			.map_or(false, |deployment_bond| deployment_bond.matured());
+		if stake_info.reputation >= MIN_REPUTATION || bond_matured {
+			Ok(stake_info)
+		} else {
+			Err(Error::<T>::LowReputation.into())
+		}
+	}
+
+	/// Resets the stake information for the given contract and stores its new delegate information.
+	///
+	fn reset_stake(
+		contract_addr: &T::AccountId,
+		delegate_info: &DelegateInfo<T>,
+		stake_info: &StakeInfo<T>,
+	) {
+		let new_stake_info = <StakeInfo<T>>::reset(stake_info);
+		PocsInfoMap::<T>::insert(
+			contract_addr,
+			PocsInfo { delegate_info: delegate_info.clone(), stake_info: new_stake_info },
+		);
+	}
+
+	/// Seeds the stake and delegate information of a contract from the genesis config.
+	///
+	/// Replaces the entry created when the contract was deployed at genesis. The reputation never
+	/// drops below [`REPUTATION_FACTOR`]. Delegate counts of validators are derived from the
+	/// seeded delegations, hence they always match [`ValidatorInfoMap`]. A contract seeded
+	/// without `delegate_to` isn't delegated.
+	///
+	pub fn seed(
+		contract_addr: &T::AccountId,
+		owner: &T::AccountId,
+		delegate_to: Option<&T::AccountId>,
+		reputation: u32,
+		stake_score: u128,
+	) {
+		if let Ok(delegate_info) = <DelegateInfo<T>>::get(contract_addr) {
+			if let Some(old_delegate_to) = &delegate_info.delegate_to {
+				Self::decrement(old_delegate_to);
+			}
+		}
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let pocs_info = PocsInfo {
+			delegate_info: DelegateInfo {
+				owner: owner.clone(),
+				delegate_to: delegate_to.cloned(),
+				delegate_at: block_number,
+			},
+			stake_info: StakeInfo {
+				reputation: reputation.max(REPUTATION_FACTOR),
+				blockheight: block_number,
+				stake_score,
+			},
+		};
+		PocsInfoMap::<T>::insert(contract_addr, pocs_info);
+		if let Some(delegate_to) = delegate_to {
+			Self::increment(delegate_to);
+		}
+	}
+
+	/// Increments the number of delegates for a validator.
+	///
+	/// Updates the delegate count for the specified validator.  
+	/// If the count reaches the minimum required delegates,  
+	/// an event is emitted indicating validation eligibility.
+	///
+	fn increment(validator: &T::AccountId) {
+		if let Ok(num_delegates) = <ValidateRequest<T>>::get(validator) {
+			let new_num_delegates = num_delegates + 1;
+			<ValidatorInfoMap<T>>::insert(&validator, new_num_delegates);
+			if new_num_delegates >= MIN_DELEGATES {
+				Pocs::<T>::deposit_event(Event::ValidateInfo {
+					validator: validator.clone(),
+					num_delegates: new_num_delegates,
+					can_validate: true,
+				})
+			} else {
+				Pocs::<T>::deposit_event(Event::ValidateInfo {
+					validator: validator.clone(),
+					num_delegates: new_num_delegates,
+					can_validate: false,
+				})
+			}
+		} else {
+			<ValidatorInfoMap<T>>::insert(&validator, 1);
+			Pocs::<T>::deposit_event(Event::ValidateInfo {
+				validator: validator.clone(),
+				num_delegates: 1,
+				can_validate: false,
+			});
+		}
+	}
+
+	/// Decrements the number of delegates for a validator.
+	///
+	/// If the validator has more than one delegate, the count is decreased.  
+	/// If the count drops below the minimum required delegates,  
+	/// an event is emitted indicating validation ineligibility.  
+	/// If no delegates remain, the validator is removed from the map.
+	///
+	fn decrement(validator: &T::AccountId) {
+		if let Ok(num_delegates) = <ValidateRequest<T>>::get(validator) {
+			if num_delegates > 1 {
+				let new_num_delegates = num_delegates - 1;
+				<ValidatorInfoMap<T>>::insert(&validator, new_num_delegates);
+				if new_num_delegates >= MIN_DELEGATES {
+					Pocs::<T>::deposit_event(Event::ValidateInfo {
+						validator: validator.clone(),
+						num_delegates: new_num_delegates,
+						can_validate: true,
+					});
+				} else {
+					Pocs::<T>::deposit_event(Event::ValidateInfo {
+						validator: validator.clone(),
+						num_delegates: new_num_delegates,
+						can_validate: false,
+					});
+				}
+			} else {
+				<ValidatorInfoMap<T>>::remove(&validator);
+				Pocs::<T>::deposit_event(Event::ValidateInfo {
+					validator: validator.clone(),
+					num_delegates: 0,
+					can_validate: false,
+				});
+			}
+		}
+	}
+
+	pub fn update_stake_owner(
+		origin: &T::AccountId,
+		contract_addr: &T::AccountId,
+		new_owner: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		Self::stake_exists(contract_addr)?;
+		let delegate_info = Self::owner_check(origin, contract_addr)?;
+		let stake_info = <DelegateRequest<T>>::min_reputation(&contract_addr)?;
+		if delegate_info.owner != *new_owner {
+			let new_delegate_info = <DelegateInfo<T>>::update_owner(&delegate_info, new_owner);
+			Self::reset_stake(contract_addr, &new_delegate_info, &stake_info);
+			Pocs::<T>::deposit_event(Event::StakeOwner {
+				contract: contract_addr.clone(),
+				new_owner: new_delegate_info.owner.clone(),
+			});
+			Ok(())
+		} else {
+			return Err(Error::<T>::AlreadyOwner.into())
+		}
+	}
+
+	/// Resolves the account ultimately controlling the stake of a contract.
+	///
+	/// Contracts instantiated by other contracts are owned by their deployer. This follows
+	/// the owner links of [`PocsInfoMap`] until an owner without delegate information,
+	/// i.e., an externally owned account, is reached. Fails if the links form a cycle or
+	/// exceed `MAX_OWNER_DEPTH` contracts.
+	///
+	pub fn root_owner(contract_addr: &T::AccountId) -> Result<T::AccountId, DispatchError> {
+		let mut visited: Vec<T::AccountId> = Vec::new();
+		let mut current = contract_addr.clone();
+		loop {
+			let owner = <DelegateInfo<T>>::get(&current)?.owner;
+			visited.push(current);
+			if !PocsInfoMap::<T>::contains_key(&owner) {
+				return Ok(owner)
+			}
+			if visited.contains(&owner) {
+				return Err(Error::<T>::StakeOwnerCycle.into())
+			}
+			if visited.len() as u32 >= MAX_OWNER_DEPTH {
+				return Err(Error::<T>::StakeOwnerDepthExceeded.into())
+			}
+			current = owner;
+		}
+	}
+}
+
+/// Represents a validation request.
+///
+/// It includes:
+/// - `validator` : For whom the validation request is made
+/// - `num_delegates` : Total number of the validator's delegate contracts.
+///
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct ValidateRequest<T: Config> {
+	validator: T::AccountId,
+	num_delegates: u32,
+}
+
+impl<T: Config> ValidateRequest<T> {
+	/// Retrieves the number of delegates for a validator.
+	///
+	pub fn get(validator: &T::AccountId) -> Result<u32, DispatchError> {
+		Pocs::<T>::get_validator_info(validator).ok_or_else(|| Error::<T>::NoValidatorFound.into())
+	}
+
+	/// Checks if a validator meets the minimum delegate requirement.
+	///
+	fn min_delegates_check(validator: &T::AccountId) -> Result<(), DispatchError> {
+		let num_delegates = Self::get(validator)?;
+		if num_delegates >= MIN_DELEGATES {
+			return Ok(())
+		} else {
+			return Err(Error::<T>::InsufficientDelegates.into())
+		}
+	}
+
+	/// Begins validation if the minimum delegate requirement is met.
+	/// Entrypoint for validation i.e., wrapper for an extrinsic function.
+	///
+	pub fn validate(validator: &T::AccountId) -> Result<(), DispatchError> {
+		Self::min_delegates_check(validator)?;
+		Ok(())
+	}
+
+	/// Returns whether a validator meets the minimum delegate requirement.
+	///
+	pub fn can_validate(validator: &T::AccountId) -> bool {
+		Self::min_delegates_check(validator).is_ok()
+	}
+
+	/// Recounts the delegates of every validator from the delegations in [`PocsInfoMap`].
+	///
+	/// Counts stored before a contract delegated to its own owner counted towards the owner
+	/// don't match the delegations. This restores the counts checked by the `try_state` hook.
+	/// Returns the number of storage entries read and written.
+	///
+	pub fn recount() -> (u64, u64) {
+		use alloc::collections::btree_map::BTreeMap;
+
+		let mut num_delegates: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+		let mut contracts = 0u64;
+		for pocs_info in PocsInfoMap::<T>::iter_values() {
+			contracts += 1;
+			if let Some(validator) = pocs_info.delegate_info.delegate_to {
+				*num_delegates.entry(validator).or_default() += 1;
+			}
+		}
+		let removed = ValidatorInfoMap::<T>::drain().count() as u64;
+		let written = num_delegates.len() as u64;
+		for (validator, count) in num_delegates {
+			ValidatorInfoMap::<T>::insert(validator, count);
+		}
+		(contracts + removed, removed + written)
+	}
+}
+
+/// The effective parameters of the PoCS protocol.
+///
+/// Combines the constants of this module with the configuration of the runtime, so that
+/// contracts can read them through the chain extensions in one call.
+///
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct PocsParams<T: Config> {
+	/// The minimum reputation required to delegate a contract.
+	pub min_reputation: u32,
+	/// The minimum number of delegates required for a validator to be eligible.
+	pub min_delegates: u32,
+	/// The reputation a contract is instantiated with.
+	pub reputation_factor: u32,
+	/// See [`Config::FailureWindow`].
+	pub failure_window: BlockNumberFor<T>,
+	/// See [`Config::FailureThreshold`].
+	pub failure_threshold: Perbill,
+	/// See [`Config::FailurePenalty`].
+	pub failure_penalty: u32,
+	/// See [`Config::CodeChangeReputationRetained`].
+	pub code_change_reputation_retained: Perbill,
+	/// See [`Config::CodeChangeStakeRetained`].
+	pub code_change_stake_retained: Perbill,
+	/// See [`Config::DeploymentBondEnabled`].
+	pub deployment_bond_enabled: bool,
+	/// See [`Config::DeploymentBondDecay`].
+	pub deployment_bond_decay: BlockNumberFor<T>,
+	/// See [`Config::DeploymentBondMaturity`].
+	pub deployment_bond_maturity: u128,
+	/// See [`Config::StakeOwnerIsOrigin`].
+	pub stake_owner_is_origin: bool,
+	/// See [`Config::StakeEventMode`].
+	pub stake_event_mode: StakeEventMode,
+}
+
+impl<T: Config> PocsParams<T> {
+	/// Retrieves the parameters currently in effect.
+	///
+	pub fn get() -> Self {
+		Self {
+			min_reputation: MIN_REPUTATION,
+			min_delegates: MIN_DELEGATES,
+			reputation_factor: REPUTATION_FACTOR,
+			failure_window: T::FailureWindow::get(),
+			failure_threshold: T::FailureThreshold::get(),
+			failure_penalty: T::FailurePenalty::get(),
+			code_change_reputation_retained: T::CodeChangeReputationRetained::get(),
+			code_change_stake_retained: T::CodeChangeStakeRetained::get(),
+			deployment_bond_enabled: T::DeploymentBondEnabled::get(),
+			deployment_bond_decay: T::DeploymentBondDecay::get(),
+			deployment_bond_maturity: T::DeploymentBondMaturity::get(),
+			stake_owner_is_origin: T::StakeOwnerIsOrigin::get(),
+			stake_event_mode: T::StakeEventMode::get(),
+		}
+	}
+}
+
+/// Checks the consistency of the PoCS storage.
+///
+/// - The delegate count of every validator in [`ValidatorInfoMap`] matches the number of
+///   contracts delegated to it. A contract its owner delegated to itself counts towards the
+///   owner, an undelegated contract counts towards nobody.
+/// - Stake only exists for live contracts.
+/// - Failure records, deployment bonds and pending stake only exist for staked contracts.
+/// - Code stake only exists for uploaded codes.
+///
+#[cfg(any(feature = "try-runtime", feature = "fuzz", test))]
+pub fn do_try_state<T: Config>() -> Result<(), sp_runtime::TryRuntimeError> {
+	use alloc::collections::btree_map::BTreeMap;
+	use frame_support::ensure;
+
+	let mut num_delegates: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+	for (contract_addr, pocs_info) in PocsInfoMap::<T>::iter() {
+		ensure!(
+			Contracts::<T>::code_hash(&contract_addr).is_some(),
+			"PoCS stake exists for a contract that isn't alive"
+		);
+		if let Some(delegate_to) = pocs_info.delegate_info.delegate_to {
+			*num_delegates.entry(delegate_to).or_default() += 1;
+		}
+	}
+	for (validator, count) in ValidatorInfoMap::<T>::iter() {
+		ensure!(
+			num_delegates.remove(&validator) == Some(count),
+			"Validator delegate count doesn't match its delegated contracts"
+		);
+	}
+	ensure!(num_delegates.is_empty(), "Delegated validator is missing from ValidatorInfoMap");
+
+	for contract_addr in FailureInfoMap::<T>::iter_keys() {
+		ensure!(
+			PocsInfoMap::<T>::contains_key(&contract_addr),
+			"Failure record exists for a contract without stake"
+		);
+	}
+	for contract_addr in DeploymentBondMap::<T>::iter_keys() {
+		ensure!(
+			PocsInfoMap::<T>::contains_key(&contract_addr),
+			"Deployment bond exists for a contract without stake"
+		);
+	}
+	for contract_addr in PendingStakeMap::<T>::iter_keys() {
+		ensure!(
+			PocsInfoMap::<T>::contains_key(&contract_addr),
+			"Pending stake exists for a contract without stake"
+		);
+	}
+	for code_hash in CodeStakeInfoMap::<T>::iter_keys() {
+		ensure!(
+			Contracts::<T>::code_owner(&code_hash).is_some(),
+			"Code stake exists for a code that isn't uploaded"
+		);
+	}
+	Ok(())
+}
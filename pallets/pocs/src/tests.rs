@@ -0,0 +1,4108 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod fuzz;
+
+use crate::{
+	chain_ext::PocsReturnCode,
+	mock::*,
+	stake::{
+		CodeStakeInfo, DelegateBatchMode, DelegateInfo, DelegateRequest, DeploymentBond,
+		FailureInfo, PendingStake, PocsParams, StakeEventMode, StakeInfo, StakeRequest,
+		ValidateRequest, INITIAL_STAKE_SCORE, MIN_DELEGATES, MIN_REPUTATION, REPUTATION_FACTOR,
+	},
+	Error, PendingStakeCount, PendingStakeMap, RecordCallFailure, ValidatorInfoMap, WeightInfo,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	assert_err, assert_ok,
+	dispatch::GetDispatchInfo,
+	storage::{unhashed, with_transaction, TransactionOutcome},
+	traits::{fungible::Mutate, OnFinalize, OnInitialize},
+	weights::Weight,
+	BoundedVec, StorageHasher, Twox64Concat,
+};
+use pallet_contracts::{
+	execution_hook::{ExecutedFrame, ExportedFunction, OnContractExecution},
+	Code, CollectEvents, DebugInfo, Determinism, Origin,
+};
+use pallet_contracts_fixtures::compile_module;
+use sp_runtime::{
+	traits::{Dispatchable, TransactionExtension},
+	AccountId32, DispatchError,
+};
+
+macro_rules! assert_return_code {
+	( $x:expr , $y:expr $(,)? ) => {{
+		assert_eq!(u32::from_le_bytes($x.data[..].try_into().unwrap()), $y as u32);
+	}};
+}
+
+/// Input of the `pocs_chain_extension` fixture calling `func_id` of the chain extension `ext_id`.
+fn chain_extension_input(ext_id: u16, func_id: u16, params: impl Encode) -> Vec<u8> {
+	let id = (ext_id as u32) << 16 | func_id as u32;
+	(id.to_le_bytes(), params).encode()
+}
+
+/// Splits the output of the `pocs_chain_extension` fixture into the return code and the output
+/// of the chain extension.
+fn chain_extension_output(data: &[u8]) -> (u32, &[u8]) {
+	let (ret_code, output) = data.split_at(4);
+	(u32::from_le_bytes(ret_code.try_into().unwrap()), output)
+}
+
+#[test]
+fn pocs_stake_maps_update_during_contract_instantiation() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Block Number at which contract instantiation takes place
+		let instantiate_block_num = 2;
+		// Initialize Contract Instantiation Block
+		initialize_block(instantiate_block_num);
+		// Instantiate Contract
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Get Stake Info of the contract
+		let stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		// Get Delegate Info of the contract
+		let delegate_info = <DelegateInfo<Test>>::get(&contract_addr).unwrap();
+		// Instantiation constants
+		assert_eq!(stake_info.stake_score(), INITIAL_STAKE_SCORE);
+		assert_eq!(stake_info.reputation(), REPUTATION_FACTOR);
+		// Instantiation Block Number
+		assert_eq!(stake_info.blockheight(), instantiate_block_num);
+		assert_eq!(delegate_info.delegate_at(), instantiate_block_num);
+		// Deployer as Owner and not delegated during instantiation
+		assert_eq!(delegate_info.owner(), ALICE);
+		assert_eq!(delegate_info.delegate_to(), None);
+	});
+}
+
+#[test]
+fn pocs_stake_maps_update_after_instantiating_uploaded_code() {
+	// Gets dummy wasm binary for uploading code and code hash for retrieval during contract instantiation
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Upload wasm binary
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			wasm,
+			Some(codec::Compact(1_000)),
+			Determinism::Enforced,
+		));
+		// Block Number at which contract instantiation takes place
+		let instantiate_block_num = 2;
+		// Initialize Contract Instantiation Block
+		initialize_block(instantiate_block_num);
+		// Instantiate Contract using code_hash from compiling dummy to wasm
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Existing(code_hash),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Get Stake Info of the contract
+		let stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		// Get Delegate Info of the contract
+		let delegate_info = <DelegateInfo<Test>>::get(&contract_addr).unwrap();
+		// Instantiation constants
+		assert_eq!(stake_info.stake_score(), INITIAL_STAKE_SCORE);
+		assert_eq!(stake_info.reputation(), REPUTATION_FACTOR);
+		// Instantiation Block Number
+		assert_eq!(stake_info.blockheight(), instantiate_block_num);
+		assert_eq!(delegate_info.delegate_at(), instantiate_block_num);
+		// Deployer as Owner and not delegated during instantiation
+		assert_eq!(delegate_info.owner(), ALICE);
+		assert_eq!(delegate_info.delegate_to(), None);
+	});
+}
+
+#[test]
+fn pocs_cannot_delegate_without_minimum_reputation() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for validator contract deployment
+	let (validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Call Contract and try updating reputation, stake score
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Requires more calls in different blocks to reach minimum reputation threshold
+		assert_err!(
+			Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), validator_addr),
+			Error::<Test>::LowReputation
+		);
+	});
+}
+
+#[test]
+fn pocs_cannot_update_owner_without_minimum_reputation() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Call Contract and try updating reputation, stake score
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Requires more calls in different blocks to reach minimum reputation threshold
+		assert_err!(
+			Pocs::update_owner(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB),
+			Error::<Test>::LowReputation
+		);
+	});
+}
+
+#[test]
+fn pocs_delegate_with_minimum_reputation() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for validator contract deployment
+	let (validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize next block to increase reputation
+		initialize_block(2);
+		// Call Contract and try updating reputation
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Initialize next block as reputation won't increase calling in same block
+		initialize_block(3);
+		// Call Contract and try updating reputation
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check if ReadyToStake event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize Block to reset events
+		initialize_block(4);
+		// Stake/Delegate our Contract to Validator Contract
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			contract_addr.clone(),
+			validator_addr.clone()
+		));
+		// Check if delegate_to of our contract is updated to validator contract address
+		assert_eq!(
+			<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(),
+			Some(validator_addr.clone())
+		);
+		// Check for events such as Delegated and ValidateInfo are emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: contract_addr.clone(),
+					delegate_to: validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: validator_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+	});
+}
+
+#[test]
+fn pocs_update_owner_with_minimum_reputation() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize next block to increase reputation
+		initialize_block(2);
+		// Call Contract and try updating reputation
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Initialize next block as reputation won't increase calling in same block
+		initialize_block(3);
+		// Call Contract and try updating reputation
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check if ReadyToStake event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize Block to reset events
+		initialize_block(4);
+		// Update our Contract Owner to BOB
+		assert_ok!(Pocs::update_owner(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// Check if owner of our contract is updated to BOB
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().owner(), BOB);
+		// Check for StakeOwner event emission
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[RuntimeEvent::Pocs(crate::Event::StakeOwner {
+				contract: contract_addr.clone(),
+				new_owner: BOB,
+			}),]
+		);
+	});
+}
+
+#[test]
+fn pocs_reputation_not_increase_in_same_block_calls() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Deposit Balances for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Call Contract (Assuming Reputation Not increases as we haven't initiated new blocks)
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Take Current Stake Info after first call
+		let init_stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		// Call Contract Again (Still in same block)
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Take Stake Info after second call
+		let new_stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		// Check that reputation is not increased due to consecutive calls in same block
+		assert!(init_stake_info.reputation() == new_stake_info.reputation());
+	});
+}
+
+#[test]
+fn pocs_cannot_delegate_by_non_deployer() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for validator contract deployment
+	let (validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Deposit Balances for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		let _ = Balances::set_balance(&BOB, 1_000_000);
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Contract (To Stake) deployed by ALICE
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Throws error if non-deployer BOB attempts to delegate the ALICE-deployed-contract
+		assert_err!(
+			Pocs::delegate(RuntimeOrigin::signed(BOB), contract_addr.clone(), validator_addr),
+			Error::<Test>::InvalidContractOwner
+		);
+	});
+}
+
+#[test]
+fn pocs_cannot_update_owner_by_non_deployer() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Deposit Balances for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&BOB, 1_000_000);
+		// Instantiate Contract (To Stake) deployed by ALICE
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Throws error if non-deployer BOB attempts to change owner of ALICE-deployed-contract
+		assert_err!(
+			Pocs::update_owner(RuntimeOrigin::signed(BOB), contract_addr.clone(), BOB),
+			Error::<Test>::InvalidContractOwner
+		);
+	});
+}
+
+#[test]
+fn pocs_cannot_delegate_an_eoa() {
+	// Gets dummy wasm binary for validator contract deployment
+	let (validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Throws Error if attempting to delegate a non-contract i.e., EOA
+		// which doesn't contain a stake or delegate info as instantiated contracts only does.
+		assert_err!(
+			Pocs::delegate(RuntimeOrigin::signed(ALICE), CHARLIE, validator_addr),
+			Error::<Test>::NoStakeExists
+		);
+	});
+}
+
+#[test]
+fn pocs_cannot_update_owner_an_eoa() {
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Throws Error if attempting to update stake owner of a non-contract i.e., EOA
+		// which doesn't contain a stake or delegate info as instantiated contracts only does.
+		assert_err!(
+			Pocs::update_owner(RuntimeOrigin::signed(ALICE), CHARLIE, BOB),
+			Error::<Test>::NoStakeExists
+		);
+	});
+}
+
+#[test]
+fn pocs_no_stake_increase_during_delegation() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for validator contract deployment
+	let (validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize new block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize New block to increase reputation during calls to contract
+		initialize_block(2);
+		// Call Contract to attain minimum reputation for delegation
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Initialize another block since reputation doesn't increase in consecutive call in same block
+		initialize_block(3);
+		// Call contract again
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Get Stake Info - includes stake score, reputation - before delegating contract for assertion after delegating
+		let before_delegate_stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		// Check ReadyToStake even emitted i.e., minimum reputation reached and contract can delegate
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize new block to reset events
+		initialize_block(4);
+		// Delegate Contract to Validator Contract
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			contract_addr.clone(),
+			validator_addr.clone()
+		));
+		// Check Delegated and ValidateInfo events emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: contract_addr.clone(),
+					delegate_to: validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: validator_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+		// Take After delegation stake info of the contract
+		let after_delegate_stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		// Check that stake score is not increased during delegation
+		assert!(
+			before_delegate_stake_info.stake_score() >= after_delegate_stake_info.stake_score()
+		);
+	});
+}
+
+#[test]
+fn pocs_stake_reset_after_delegation() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for validator contract deployment
+	let (validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize new block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate New Block for attaining mininmum reputation
+		initialize_block(2);
+		// Call Contract
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Instantiate New Block for attaining mininmum reputation
+		initialize_block(3);
+		// Call Contract again
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check ReadToStake Event is emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize block to reset events
+		initialize_block(4);
+		// Delegate contract to validator contract
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			contract_addr.clone(),
+			validator_addr.clone()
+		));
+		// Check if Delegated and ValidateInfo events are emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: contract_addr.clone(),
+					delegate_to: validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: validator_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+		// Get stake score of contract and check if it resets to initial stake score constant
+		assert_eq!(
+			<StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score(),
+			INITIAL_STAKE_SCORE
+		);
+	});
+}
+
+#[test]
+fn pocs_stake_reset_after_update_owner() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize new block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate New Block for attaining mininmum reputation
+		initialize_block(2);
+		// Call Contract
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Instantiate New Block for attaining mininmum reputation
+		initialize_block(3);
+		// Call Contract again
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check ReadToStake Event is emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize block to reset events
+		initialize_block(4);
+		// Update contract owner to BOB
+		assert_ok!(Pocs::update_owner(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// Check if StakeOwner event is emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[RuntimeEvent::Pocs(crate::Event::StakeOwner {
+				contract: contract_addr.clone(),
+				new_owner: BOB,
+			}),]
+		);
+		// Get stake score of contract and check if it resets to initial stake score constant
+		assert_eq!(
+			<StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score(),
+			INITIAL_STAKE_SCORE
+		);
+	});
+}
+
+#[test]
+fn pocs_redundant_update_owner_fails() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize New Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize new block for reputation increment criteria
+		initialize_block(2);
+		// Call Contract, expect reputation increment
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Initialize new block since consecutive calls under same block halts reputation increment
+		initialize_block(3);
+		// Call Contract again, expect minimum reputation attained
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check ReadyToStake event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize Block to reset events
+		initialize_block(4);
+		// Update contract owner to BOB
+		assert_ok!(Pocs::update_owner(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// Check if StakeOwner event is emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[RuntimeEvent::Pocs(crate::Event::StakeOwner {
+				contract: contract_addr.clone(),
+				new_owner: BOB,
+			}),]
+		);
+		// Update contract owner to same owner again to expect error thrown
+		assert_err!(
+			Pocs::update_owner(RuntimeOrigin::signed(BOB), contract_addr.clone(), BOB),
+			Error::<Test>::AlreadyOwner
+		);
+	});
+}
+
+#[test]
+fn pocs_redundant_delegate_fails() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for validator contract deployment
+	let (validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize New Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize new block for reputation increment criteria
+		initialize_block(2);
+		// Call Contract, expect reputation increment
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Initialize new block since consecutive calls under same block halts reputation increment
+		initialize_block(3);
+		// Call Contract again, expect minimum reputation attained
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check ReadyToStake event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize Block to reset events
+		initialize_block(4);
+		// Delegate Contract to Validator Contract
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			contract_addr.clone(),
+			validator_addr.clone()
+		));
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: contract_addr.clone(),
+					delegate_to: validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: validator_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+		// Delegate contract to same validator contract again to expect error thrown
+		assert_err!(
+			Pocs::delegate(
+				RuntimeOrigin::signed(ALICE),
+				contract_addr.clone(),
+				validator_addr.clone()
+			),
+			Error::<Test>::AlreadyDelegated
+		);
+	});
+}
+
+#[test]
+fn pocs_validator_delegate_count_increment() {
+	// Gets dummy wasm binary for first contract deployment
+	let (first_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for second contract deployment
+	let (second_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for validator contract deployment
+	let (validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize New Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&DJANGO, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate First Contract (To Stake)
+		let first_contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(first_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Second Contract (To Stake)
+		let second_contract_addr = Contracts::bare_instantiate(
+			DJANGO,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(second_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize Block, Expect Reputation increase for two contracts during calling
+		initialize_block(2);
+		// Call First Contract
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				first_contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Call Second Contract
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				second_contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Initialize Block, Expect minimum reputation achieved
+		initialize_block(3);
+		// Call First Contract Again
+		assert_ok!(
+			Contracts::bare_call(
+				DJANGO,
+				first_contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check ReadyToStake event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == first_contract_addr
+		)));
+		// Initialize Block to reset events
+		initialize_block(4);
+		// Call Second Contract Again, epxect minimum reputation reached
+		assert_ok!(
+			Contracts::bare_call(
+				DJANGO,
+				second_contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check if ReadyToStake event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == second_contract_addr
+		)));
+		// Initialize Block to reset events
+		initialize_block(5);
+		// Dlegate First Contract to Validator Contract
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			first_contract_addr.clone(),
+			validator_addr.clone()
+		));
+		// Check if Validator's Number of Delegates is initialized
+		assert_eq!(<ValidateRequest<Test>>::get(&validator_addr).unwrap(), 1);
+		// Check Delegated and ValidateInfo event emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: first_contract_addr.clone(),
+					delegate_to: validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: validator_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+		// Initialize Block to reset events
+		initialize_block(6);
+		// Delegate Second Contract to Validator Contract
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(DJANGO),
+			second_contract_addr.clone(),
+			validator_addr.clone()
+		));
+		// Check if the Validator's number of delegates is incremented
+		assert_eq!(<ValidateRequest<Test>>::get(&validator_addr).unwrap(), 2);
+		// Check if Delegated and ValidateInfo events are emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: second_contract_addr.clone(),
+					delegate_to: validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: validator_addr.clone(),
+					num_delegates: 2,
+					can_validate: false,
+				}),
+			]
+		);
+	});
+}
+
+#[test]
+fn pocs_validator_delegate_count_decrement() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for first validator contract deployment
+	let (first_validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for first validator contract deployment
+	let (second_validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize New Block
+		initialize_block(1);
+		// Deposit Balances for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		let _ = Balances::set_balance(&DJANGO, 1_000_000);
+		// Instantiate First Validator Contract
+		let first_validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(first_validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Second Validator Contract
+		let second_validator_addr = Contracts::bare_instantiate(
+			DJANGO,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(second_validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize New Block to meet reputation criteria
+		initialize_block(2);
+		// Call Contract, expect reputation increase
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// initialize New block to reset events
+		initialize_block(3);
+		// Call Contract again, expect minimum reputation attained
+		assert_ok!(
+			Contracts::bare_call(
+				CHARLIE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check ReadyToStake event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize Block to reset events
+		initialize_block(4);
+		// Delegate Contract to First Validator Address
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			contract_addr.clone(),
+			first_validator_addr.clone()
+		));
+		// Check if Validator's number of delegates initialized
+		assert_eq!(<ValidateRequest<Test>>::get(&first_validator_addr).unwrap(), 1);
+		// Check Delegated and ValidateInfo events are emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: contract_addr.clone(),
+					delegate_to: first_validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: first_validator_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+		// Initialize new block to reset events
+		initialize_block(5);
+		// Delegate Contract to Second Validator Address
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			contract_addr.clone(),
+			second_validator_addr.clone()
+		));
+		// Ensure the first validator number of delegates became zero hence, not a validator
+		assert_err!(
+			<ValidateRequest<Test>>::get(&first_validator_addr),
+			Error::<Test>::NoValidatorFound
+		);
+		// Ensure the second validator number of delegates is initialized
+		assert_eq!(
+			<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(),
+			Some(second_validator_addr.clone())
+		);
+		assert_eq!(<ValidateRequest<Test>>::get(&second_validator_addr).unwrap(), 1);
+		// Check Delegated and ValidateInfo events are emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: contract_addr.clone(),
+					delegate_to: second_validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: first_validator_addr.clone(),
+					num_delegates: 0,
+					can_validate: false,
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: second_validator_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+	});
+}
+
+#[test]
+fn pocs_stake_score_increment_during_call_pseudo_test() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize new block
+		initialize_block(1);
+		// Deposit Balances for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		// Instantiate Contract
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm.clone()),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize new block, for reputation increase
+		initialize_block(2);
+		// Call contract, expect reputation increase
+		assert_ok!(
+			Contracts::bare_call(
+				CHARLIE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Initialize new block to reset events
+		initialize_block(3);
+		// Call Contract, expect minimum reputation reached
+		assert_ok!(
+			Contracts::bare_call(
+				CHARLIE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check ReadyToStake event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize new block to reset events
+		initialize_block(4);
+		// Delegate Contract to Validator Contract
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			contract_addr.clone(),
+			validator_addr.clone()
+		));
+		// Check Delegated and ValidateInfo events emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: contract_addr.clone(),
+					delegate_to: validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: validator_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+		// Get Stake Score of contract after Delegation
+		let init_stake_score = <StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score();
+		// Initialize new block to reset events
+		initialize_block(5);
+		// Execute First Call of contract after delegation
+		assert_ok!(
+			Contracts::bare_call(
+				CHARLIE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Get Stake Score of contract after first call (recent call)
+		// !!! Need to calulate stake score instead of getting
+		// Requires creating a new stack frame to execute and provide
+		// gas info. Hence it is written as a pseudo test : PoCS v0.2 !!!
+		let first_call_stake_score = <StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score();
+		// Check if first call stake score is incremented
+		assert!(first_call_stake_score > init_stake_score);
+		// Check Staked event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::Staked { contract, stake_score })
+				if *contract == contract_addr && *stake_score == first_call_stake_score
+		)));
+		// Initialize new block to reset events
+		initialize_block(5);
+		// Execute Second Call of contract after delegation
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Get Stake Score of contracta after second call (recent call)
+		let second_call_stake_score = <StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score();
+		// Check second call stake score is increment
+		assert!(second_call_stake_score > first_call_stake_score);
+		// Check Staked event emitted (!!! pseudo test !!!)
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::Staked { contract, stake_score })
+				if *contract == contract_addr && *stake_score == second_call_stake_score
+		)));
+	});
+}
+
+#[test]
+fn pocs_maps_update_when_a_contract_instantiates_new_contract() {
+	// Get caller wasm binary for caller contract deployment which is called to instantiate another existing code hash
+	let (caller_wasm, _) = compile_module::<Test>("caller_contract").unwrap();
+	// Get callee wasm binary which returns with data for uploading code and its code_hash for instantiating via calling caller contract
+	let (callee_wasm, callee_code_hash) = compile_module::<Test>("return_with_data").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(1).build_and_execute(|| {
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Caller Contract
+		let caller_addr = Contracts::bare_instantiate(
+			ALICE,
+			100_000,
+			GAS_LIMIT,
+			None,
+			Code::Upload(caller_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Upload Callee Wasm binary
+		Contracts::bare_upload_code(ALICE, callee_wasm, None, Determinism::Enforced).unwrap();
+		let callee_addr = Contracts::contract_address(
+			&caller_addr,
+			&callee_code_hash,
+			&[0, 1, 34, 51, 68, 85, 102, 119], // hard coded in wasm
+			&[],
+		);
+		// Call Caller Contract which deploys callee contract
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			caller_addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			callee_code_hash.as_ref().to_vec(),
+		));
+		// Check if maps are updated for callee contract
+		assert_ok!(<StakeInfo<Test>>::get(&callee_addr));
+		assert_ok!(<DelegateInfo<Test>>::get(&callee_addr));
+	});
+}
+
+#[test]
+fn pocs_contract_as_deployer_as_stake_owner_not_signer() {
+	// Get caller wasm binary for caller contract deployment which is called to instantiate another existing code hash
+	let (caller_wasm, _) = compile_module::<Test>("caller_contract").unwrap();
+	// Get callee wasm binary which returns with data for uploading code and its code_hash for instantiating via calling caller contract
+	let (callee_wasm, callee_code_hash) = compile_module::<Test>("return_with_data").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(1).build_and_execute(|| {
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Caller Contract
+		let caller_addr = Contracts::bare_instantiate(
+			ALICE,
+			100_000,
+			GAS_LIMIT,
+			None,
+			Code::Upload(caller_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Upload Callee Wasm binary
+		Contracts::bare_upload_code(ALICE, callee_wasm, None, Determinism::Enforced).unwrap();
+		let callee_addr = Contracts::contract_address(
+			&caller_addr,
+			&callee_code_hash,
+			&[0, 1, 34, 51, 68, 85, 102, 119], // hard coded in wasm
+			&[],
+		);
+		// Call Caller Contract which deploys callee contract
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			caller_addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			callee_code_hash.as_ref().to_vec(),
+		));
+		// Check if Caller is the owner of the callee as Caller instantiated callee
+		assert_eq!(<DelegateInfo<Test>>::get(&callee_addr).unwrap().owner(), caller_addr);
+	});
+}
+
+#[test]
+fn pocs_stake_score_increment_during_contract_delegate_call() {
+	// Gets caller wasm binary for caller contract deployment
+	let (wasm_caller, _) = compile_module::<Test>("call").unwrap();
+	// Gets dummy wasm binary for callee contract deployment
+	let (wasm_callee, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize New Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Caller Contract
+		let caller_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm_caller),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Callee Contract
+		let callee_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm_callee),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize Block adhering for Reputation Criteria
+		initialize_block(2);
+		// Call callee Contract
+		assert_ok!(
+			Contracts::bare_call(
+				CHARLIE,
+				callee_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Initialize Block to reset events
+		initialize_block(3);
+		// Call callee contract, expect minimum reputation attained
+		assert_ok!(
+			Contracts::bare_call(
+				CHARLIE,
+				callee_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check for ReadyToStake event
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == callee_addr
+		)));
+		// Initialize new block to reset events
+		initialize_block(4);
+		// Delegate Calle contract to Caller Contract, Since after delegation only the stake score increases
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			callee_addr.clone(),
+			caller_addr.clone()
+		));
+		// Check Delegated and ValidateInfo events emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: callee_addr.clone(),
+					delegate_to: caller_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: caller_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+		// Get stake score of callee contract after delegation
+		let init_stake_score = <StakeInfo<Test>>::get(&callee_addr).unwrap().stake_score();
+		// Initialize Block to reset events
+		initialize_block(5);
+		// Call caller contract and pass callee contract address to do delegate call
+		assert_ok!(Contracts::call(
+			RuntimeOrigin::signed(ALICE),
+			caller_addr,
+			0,
+			GAS_LIMIT,
+			None,
+			(100u32, &callee_addr).encode()
+		));
+		// Get stake score of callee contract after recent delegate call from caller contract
+		let new_stake_score = <StakeInfo<Test>>::get(&callee_addr).unwrap().stake_score();
+		// Check if new stake score is incremented
+		assert!(new_stake_score > init_stake_score);
+		// Check Staked event emitted (!!! pseudo test !!! )
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::Staked { contract, stake_score })
+				if *contract == callee_addr && *stake_score == new_stake_score
+		)));
+	});
+}
+
+#[test]
+fn pocs_validator_stays_same_after_update_owner() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Gets dummy wasm binary for validator contract deployment
+	let (validator_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		// Instantiate Validator Contract
+		let validator_addr = Contracts::bare_instantiate(
+			CHARLIE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(validator_wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Instantiate Contract (To Stake)
+		let contract_addr = Contracts::bare_instantiate(
+			ALICE,
+			0,
+			GAS_LIMIT,
+			None,
+			Code::Upload(wasm),
+			vec![],
+			vec![],
+			DebugInfo::Skip,
+			CollectEvents::Skip,
+		)
+		.result
+		.unwrap()
+		.account_id;
+		// Initialize next block to increase reputation
+		initialize_block(2);
+		// Call Contract and try updating reputation
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Initialize next block as reputation won't increase calling in same block
+		initialize_block(3);
+		// Call Contract and try updating reputation
+		assert_ok!(
+			Contracts::bare_call(
+				ALICE,
+				contract_addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				vec![],
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+				Determinism::Enforced
+			)
+			.result
+		);
+		// Check if ReadyToStake event emitted
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReadyToStake { contract })
+				if *contract == contract_addr
+		)));
+		// Initialize Block to reset events
+		initialize_block(4);
+		// Stake/Delegate our Contract to Validator Contract
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			contract_addr.clone(),
+			validator_addr.clone()
+		));
+		// Check if delegate_to of our contract is updated to validator contract address
+		assert_eq!(
+			<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(),
+			Some(validator_addr.clone())
+		);
+		// Check for events such as Delegated and ValidateInfo are emitted
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Pocs(crate::Event::Delegated {
+					contract: contract_addr.clone(),
+					delegate_to: validator_addr.clone(),
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: validator_addr.clone(),
+					num_delegates: 1,
+					can_validate: false,
+				}),
+			]
+		);
+		// Initialize block to reset events
+		initialize_block(5);
+		// Update Owner for our Contract to BOB
+		assert_ok!(Pocs::update_owner(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// Assert if the owner of our contract is changed
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().owner(), BOB);
+		// Assert if Validator Num delegates is unchanged
+		assert_eq!(<ValidateRequest<Test>>::get(&validator_addr).unwrap(), 1);
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[RuntimeEvent::Pocs(crate::Event::StakeOwner {
+				contract: contract_addr.clone(),
+				new_owner: BOB,
+			}),]
+		);
+	});
+}
+
+#[test]
+fn pocs_reputation_penalized_when_failure_rate_exceeds_threshold() {
+	// Gets wasm binary which succeeds, reverts or traps depending on its input
+	let (wasm, _) = compile_module::<Test>("ok_trap_revert").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		// Call Contract successfully in two different blocks to increase reputation
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).data(0u32.encode()).build_and_unwrap_result();
+		}
+		assert_eq!(<StakeInfo<Test>>::get(&contract_addr).unwrap().reputation(), 3);
+		// Trap the contract thrice i.e., 3 out of 5 executions within the window failed
+		for _ in 0..3 {
+			assert_err!(
+				builder::bare_call(contract_addr.clone()).data(2u32.encode()).build().result,
+				pallet_contracts::Error::<Test>::ContractTrapped,
+			);
+		}
+		// Failures are recorded although the trapped frames were rolled back
+		let failure_info = <FailureInfo<Test>>::get(&contract_addr).unwrap();
+		assert_eq!(failure_info.calls(), 5);
+		assert_eq!(failure_info.failures(), 3);
+		assert_eq!(failure_info.window_start(), 2);
+		// Reputation is untouched until the window elapses
+		assert_eq!(<StakeInfo<Test>>::get(&contract_addr).unwrap().reputation(), 3);
+		// Revert the contract after the window has elapsed
+		let window_end = 2 + FailureWindow::get();
+		initialize_block(window_end);
+		let result =
+			builder::bare_call(contract_addr.clone()).data(1u32.encode()).build_and_unwrap_result();
+		assert!(result.did_revert());
+		// Reputation is penalized and a new window is opened with the latest outcome
+		assert_eq!(<StakeInfo<Test>>::get(&contract_addr).unwrap().reputation(), 2);
+		let failure_info = <FailureInfo<Test>>::get(&contract_addr).unwrap();
+		assert_eq!(failure_info.calls(), 1);
+		assert_eq!(failure_info.failures(), 1);
+		assert_eq!(failure_info.window_start(), window_end);
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReputationPenalized {
+				contract, calls: 5, failures: 3, reputation: 2
+			}) if *contract == contract_addr
+		)));
+	});
+}
+
+#[test]
+fn pocs_reputation_not_penalized_below_failure_threshold() {
+	// Gets wasm binary which succeeds, reverts or traps depending on its input
+	let (wasm, _) = compile_module::<Test>("ok_trap_revert").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		// Call Contract successfully in two different blocks to increase reputation
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).data(0u32.encode()).build_and_unwrap_result();
+		}
+		// Trap the contract once i.e., 1 out of 3 executions within the window failed
+		assert_err!(
+			builder::bare_call(contract_addr.clone()).data(2u32.encode()).build().result,
+			pallet_contracts::Error::<Test>::ContractTrapped,
+		);
+		// Trap the contract again after the window has elapsed
+		initialize_block(2 + FailureWindow::get());
+		assert_err!(
+			builder::bare_call(contract_addr.clone()).data(2u32.encode()).build().result,
+			pallet_contracts::Error::<Test>::ContractTrapped,
+		);
+		// Reputation is retained as the failure rate stayed below the threshold
+		assert_eq!(<StakeInfo<Test>>::get(&contract_addr).unwrap().reputation(), 3);
+		assert!(!System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::ReputationPenalized { .. })
+		)));
+	});
+}
+
+#[test]
+fn pocs_failed_call_extrinsic_is_recorded() {
+	// Gets wasm binary which succeeds, reverts or traps depending on its input
+	let (wasm, _) = compile_module::<Test>("ok_trap_revert").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		// Call Contract successfully to record a first outcome
+		builder::bare_call(contract_addr.clone()).data(0u32.encode()).build_and_unwrap_result();
+		// Trap the contract through the `call` extrinsic
+		let origin = RuntimeOrigin::signed(ALICE);
+		let call = RuntimeCall::Contracts(pallet_contracts::Call::call {
+			dest: contract_addr.clone(),
+			value: 0,
+			gas_limit: GAS_LIMIT,
+			storage_deposit_limit: None,
+			data: 2u32.encode(),
+		});
+		let info = call.get_dispatch_info();
+		let pre = RecordCallFailure::<Test>::new().prepare((), &origin, &call, &info, 0).unwrap();
+		assert_eq!(pre, Some(contract_addr.clone()));
+		let (mut post_info, result) = match call.dispatch(origin) {
+			Ok(post_info) => (post_info, Ok(())),
+			Err(error) => (error.post_info, Err(error.error)),
+		};
+		assert_err!(result, pallet_contracts::Error::<Test>::ContractTrapped);
+		// The failure recorded during the dispatch was rolled back with the extrinsic
+		let failure_info = <FailureInfo<Test>>::get(&contract_addr).unwrap();
+		assert_eq!(failure_info.calls(), 1);
+		assert_eq!(failure_info.failures(), 0);
+		// The extension records it after the dispatch
+		assert_ok!(RecordCallFailure::<Test>::post_dispatch(
+			pre,
+			&info,
+			&mut post_info,
+			0,
+			&result
+		));
+		let failure_info = <FailureInfo<Test>>::get(&contract_addr).unwrap();
+		assert_eq!(failure_info.calls(), 2);
+		assert_eq!(failure_info.failures(), 1);
+	});
+}
+
+#[test]
+fn pocs_out_of_gas_call_is_not_recorded() {
+	// Gets wasm binary which loops until it runs out of gas
+	let (wasm, _) = compile_module::<Test>("run_out_of_gas").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		// Running out of gas is up to the caller, not a failure of the contract
+		assert_err!(
+			builder::bare_call(contract_addr.clone()).build().result,
+			pallet_contracts::Error::<Test>::OutOfGas,
+		);
+		assert_err!(<FailureInfo<Test>>::get(&contract_addr), Error::<Test>::NoStakeExists);
+		// Neither is it recorded after a `call` extrinsic running out of gas
+		let origin = RuntimeOrigin::signed(ALICE);
+		let call = RuntimeCall::Contracts(pallet_contracts::Call::call {
+			dest: contract_addr.clone(),
+			value: 0,
+			gas_limit: GAS_LIMIT,
+			storage_deposit_limit: None,
+			data: vec![],
+		});
+		let info = call.get_dispatch_info();
+		let pre = RecordCallFailure::<Test>::new().prepare((), &origin, &call, &info, 0).unwrap();
+		let error = call.dispatch(origin).unwrap_err();
+		assert_eq!(error.error, pallet_contracts::Error::<Test>::OutOfGas.into());
+		let mut post_info = error.post_info;
+		assert_ok!(RecordCallFailure::<Test>::post_dispatch(
+			pre,
+			&info,
+			&mut post_info,
+			0,
+			&Err(error.error),
+		));
+		assert_err!(<FailureInfo<Test>>::get(&contract_addr), Error::<Test>::NoStakeExists);
+	});
+}
+
+#[test]
+fn pocs_stake_reset_when_contract_sets_code_hash() {
+	// Gets wasm binary which replaces its own code with the code hash passed as input
+	let (wasm, code_hash) = compile_module::<Test>("set_code_hash").unwrap();
+	// Gets wasm binary of the replacement code
+	let (new_wasm, new_code_hash) = compile_module::<Test>("new_set_code_hash_contract").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract and upload the replacement code
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			new_wasm,
+			None,
+			Determinism::Enforced
+		));
+		// Setting the same code hash isn't a code change, hence reputation increases
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone())
+				.data(code_hash.as_ref().to_vec())
+				.build_and_unwrap_result();
+		}
+		// Delegate the matured contract to earn stake score
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		initialize_block(4);
+		builder::bare_call(contract_addr.clone())
+			.data(code_hash.as_ref().to_vec())
+			.build_and_unwrap_result();
+		let stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		assert_eq!(stake_info.reputation(), 4);
+		assert!(stake_info.stake_score() > INITIAL_STAKE_SCORE);
+		// Swap in entirely different logic via `seal_set_code_hash`
+		initialize_block(5);
+		let result = builder::bare_call(contract_addr.clone())
+			.data(new_code_hash.as_ref().to_vec())
+			.build_and_unwrap_result();
+		assert_return_code!(result, 1);
+		// Reputation and stake score earned with the previous code are not retained
+		let stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		assert_eq!(stake_info.reputation(), REPUTATION_FACTOR);
+		assert_eq!(stake_info.stake_score(), INITIAL_STAKE_SCORE);
+		assert_eq!(stake_info.blockheight(), 5);
+		// Delegation is cleared until the new code reaches minimum reputation
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(), None);
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), None);
+		assert_err!(
+			Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB),
+			Error::<Test>::LowReputation
+		);
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::Undelegated { contract, delegate_to })
+				if *contract == contract_addr && *delegate_to == BOB
+		)));
+		assert!(System::events().iter().any(|event| matches!(
+			&event.event,
+			RuntimeEvent::Pocs(crate::Event::StakeResetOnCodeChange {
+				contract, reputation, stake_score
+			}) if *contract == contract_addr &&
+				*reputation == REPUTATION_FACTOR &&
+				*stake_score == INITIAL_STAKE_SCORE
+		)));
+	});
+}
+
+#[test]
+fn pocs_stake_reset_when_root_sets_code() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Gets wasm binary of the replacement code
+	let (new_wasm, new_code_hash) = compile_module::<Test>("crypto_hashes").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract and upload the replacement code
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			new_wasm,
+			None,
+			Determinism::Enforced
+		));
+		// Call Contract in different blocks to reach minimum reputation
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		// Delegate the matured contract and earn stake score
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		initialize_block(4);
+		builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		assert!(
+			<StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score() > INITIAL_STAKE_SCORE
+		);
+		// Root replaces the code of the contract
+		initialize_block(5);
+		assert_ok!(Contracts::set_code(
+			RuntimeOrigin::root(),
+			contract_addr.clone(),
+			new_code_hash
+		));
+		// Reputation and stake score earned with the previous code are not retained
+		let stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		assert_eq!(stake_info.reputation(), REPUTATION_FACTOR);
+		assert_eq!(stake_info.stake_score(), INITIAL_STAKE_SCORE);
+		assert_eq!(
+			System::events().iter().map(|e| e.event.clone()).collect::<Vec<_>>(),
+			[
+				RuntimeEvent::Contracts(pallet_contracts::Event::ContractCodeUpdated {
+					contract: contract_addr.clone(),
+					new_code_hash,
+					old_code_hash: code_hash,
+				}),
+				RuntimeEvent::Pocs(crate::Event::StakeResetOnCodeChange {
+					contract: contract_addr.clone(),
+					reputation: REPUTATION_FACTOR,
+					stake_score: INITIAL_STAKE_SCORE,
+				}),
+				RuntimeEvent::Pocs(crate::Event::Undelegated {
+					contract: contract_addr.clone(),
+					delegate_to: BOB,
+				}),
+				RuntimeEvent::Pocs(crate::Event::ValidateInfo {
+					validator: BOB,
+					num_delegates: 0,
+					can_validate: false,
+				}),
+			]
+		);
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(), None);
+	});
+}
+
+#[test]
+fn pocs_code_stake_accrues_to_library_uploader_on_delegate_call() {
+	// Gets wasm binaries of the calling contract and the library code
+	let (caller_wasm, caller_code_hash) = compile_module::<Test>("delegate_call").unwrap();
+	let (callee_wasm, callee_code_hash) = compile_module::<Test>("delegate_call_lib").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(500).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&BOB, 1_000_000);
+		// Instantiate the caller contract by ALICE
+		let caller_addr = builder::bare_instantiate(Code::Upload(caller_wasm))
+			.value(300_000)
+			.build_and_unwrap_account_id();
+		// Only upload the library code by BOB
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(BOB),
+			callee_wasm,
+			None,
+			Determinism::Enforced,
+		));
+		// No code stake exists before the library is executed
+		assert!(<CodeStakeInfo<Test>>::get(&callee_code_hash).is_err());
+		// Delegate call into the library code
+		assert_ok!(builder::call(caller_addr.clone())
+			.value(1337)
+			.data(callee_code_hash.as_ref().to_vec())
+			.build());
+		// The library code accrues stake owned by its uploader
+		let code_stake_info = <CodeStakeInfo<Test>>::get(&callee_code_hash).unwrap();
+		assert_eq!(code_stake_info.owner(), BOB);
+		assert_eq!(code_stake_info.reputation(), REPUTATION_FACTOR);
+		assert!(code_stake_info.stake_score() > INITIAL_STAKE_SCORE);
+		// The calling contract's code accrues stake owned by its uploader
+		assert_eq!(<CodeStakeInfo<Test>>::get(&caller_code_hash).unwrap().owner(), ALICE);
+		// Removing the library code also removes its stake
+		assert_ok!(Contracts::remove_code(RuntimeOrigin::signed(BOB), callee_code_hash));
+		assert!(<CodeStakeInfo<Test>>::get(&callee_code_hash).is_err());
+	});
+}
+
+#[test]
+fn pocs_code_stake_accrues_across_contract_instances() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate two contracts sharing the same code
+		let first_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let second_addr = builder::bare_instantiate(Code::Existing(code_hash))
+			.salt(vec![1])
+			.build_and_unwrap_account_id();
+		// Instantiation does not accrue code stake
+		assert!(<CodeStakeInfo<Test>>::get(&code_hash).is_err());
+		// Call the first instance
+		initialize_block(2);
+		builder::bare_call(first_addr.clone()).build_and_unwrap_result();
+		let first_stake_score = <CodeStakeInfo<Test>>::get(&code_hash).unwrap().stake_score();
+		// Call the second instance in a later block
+		initialize_block(3);
+		builder::bare_call(second_addr.clone()).build_and_unwrap_result();
+		// The code stake combines the usage of both instances
+		let code_stake_info = <CodeStakeInfo<Test>>::get(&code_hash).unwrap();
+		assert_eq!(code_stake_info.owner(), ALICE);
+		assert_eq!(code_stake_info.reputation(), REPUTATION_FACTOR * 2);
+		assert!(code_stake_info.stake_score() > first_stake_score);
+		// Each instance keeps its own contract stake
+		assert_eq!(
+			<StakeInfo<Test>>::get(&first_addr).unwrap().reputation(),
+			REPUTATION_FACTOR * 2
+		);
+		assert_eq!(
+			<StakeInfo<Test>>::get(&second_addr).unwrap().reputation(),
+			REPUTATION_FACTOR * 2
+		);
+	});
+}
+
+#[test]
+fn pocs_code_stake_accrues_for_non_delegated_instances() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate a contract which is never delegated
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		assert!(!<DelegateInfo<Test>>::get(&contract_addr).unwrap().is_delegated());
+		// Call the contract in a later block
+		initialize_block(2);
+		builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		// The code accrues stake owned by its uploader regardless of the delegation
+		let code_stake_info = <CodeStakeInfo<Test>>::get(&code_hash).unwrap();
+		assert_eq!(code_stake_info.owner(), ALICE);
+		assert_eq!(code_stake_info.reputation(), REPUTATION_FACTOR);
+		assert!(code_stake_info.stake_score() > INITIAL_STAKE_SCORE);
+		// The contract itself still isn't delegated
+		assert!(!<DelegateInfo<Test>>::get(&contract_addr).unwrap().is_delegated());
+	});
+}
+
+#[test]
+fn pocs_deployment_bond_records_constructor_gas() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Gets wasm binary of the replacement code
+	let (new_wasm, new_code_hash) = compile_module::<Test>("crypto_hashes").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract while deployment bonds are disabled
+		let unbonded_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		assert!(<DeploymentBond<Test>>::get(&unbonded_addr).is_err());
+		// Instantiate Contract while deployment bonds are enabled
+		DeploymentBondEnabled::set(true);
+		let contract_addr = builder::bare_instantiate(Code::Existing(code_hash))
+			.salt(vec![1])
+			.build_and_unwrap_account_id();
+		// Constructor gas is recorded as bond without adding to the stake score
+		let deployment_bond = <DeploymentBond<Test>>::get(&contract_addr).unwrap();
+		assert!(deployment_bond.bond() > 0);
+		assert_eq!(deployment_bond.bonded_at(), 1);
+		assert_eq!(
+			<StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score(),
+			INITIAL_STAKE_SCORE
+		);
+		assert!(System::events().iter().any(|event| {
+			event.event ==
+				RuntimeEvent::Pocs(crate::Event::DeploymentBonded {
+					contract: contract_addr.clone(),
+					bond: deployment_bond.bond(),
+				})
+		}));
+		// The bond linearly decays over the configured number of blocks
+		DeploymentBondDecay::set(10);
+		initialize_block(6);
+		assert_eq!(deployment_bond.effective_bond(), deployment_bond.bond() / 2);
+		initialize_block(11);
+		assert_eq!(deployment_bond.effective_bond(), 0);
+		// Bond is dropped when the contract's code is replaced
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			new_wasm,
+			None,
+			Determinism::Enforced
+		));
+		assert_ok!(Contracts::set_code(
+			RuntimeOrigin::root(),
+			contract_addr.clone(),
+			new_code_hash
+		));
+		assert!(<DeploymentBond<Test>>::get(&contract_addr).is_err());
+	});
+}
+
+#[test]
+fn pocs_deployment_bond_not_recorded_for_calls() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract and drop its stake, like that of a contract predating PoCS
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		StakeRequest::<Test>::delete(&contract_addr);
+		assert!(<StakeInfo<Test>>::get(&contract_addr).is_err());
+		// First execution of the contract is a call while deployment bonds are enabled
+		DeploymentBondEnabled::set(true);
+		assert_ok!(builder::bare_call(contract_addr.clone()).build().result);
+		// Stake is created without recording the call's gas as bond
+		assert_eq!(<StakeInfo<Test>>::get(&contract_addr).unwrap().reputation(), REPUTATION_FACTOR);
+		assert!(<DeploymentBond<Test>>::get(&contract_addr).is_err());
+		assert!(!System::events().iter().any(|event| {
+			matches!(event.event, RuntimeEvent::Pocs(crate::Event::DeploymentBonded { .. }))
+		}));
+	});
+}
+
+#[test]
+fn pocs_delegate_with_matured_deployment_bond() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Enable deployment bonds that mature above a single unit of gas
+		DeploymentBondEnabled::set(true);
+		DeploymentBondDecay::set(10);
+		DeploymentBondMaturity::set(1);
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts sharing the same code
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let decayed_addr = builder::bare_instantiate(Code::Existing(code_hash))
+			.salt(vec![1])
+			.build_and_unwrap_account_id();
+		// Matured bond is announced as ready to stake
+		assert!(System::events().iter().any(|event| {
+			event.event ==
+				RuntimeEvent::Pocs(crate::Event::ReadyToStake {
+					contract: contract_addr.clone(),
+				})
+		}));
+		// Matured bond allows delegation without minimum reputation
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// Fully decayed bond no longer counts towards maturity
+		initialize_block(11);
+		assert_err!(
+			Pocs::delegate(RuntimeOrigin::signed(ALICE), decayed_addr.clone(), BOB),
+			Error::<Test>::LowReputation
+		);
+	});
+}
+
+#[test]
+fn pocs_stake_owner_resolves_to_factory_deployer() {
+	// Gets wasm binaries of the factory contract and the deployed contract
+	let (factory_wasm, _) = compile_module::<Test>("instantiate_return_code").unwrap();
+	let (callee_wasm, callee_hash) = compile_module::<Test>("ok_trap_revert").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(50).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate the factory contract and upload the code it deploys
+		let factory_addr = builder::bare_instantiate(Code::Upload(factory_wasm))
+			.value(100_000)
+			.build_and_unwrap_account_id();
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			callee_wasm,
+			None,
+			Determinism::Enforced
+		));
+		// The factory instantiates a new contract
+		let input: Vec<u8> =
+			callee_hash.as_ref().iter().chain(&0u32.to_le_bytes()).cloned().collect();
+		let result =
+			builder::bare_call(factory_addr.clone()).data(input.clone()).build_and_unwrap_result();
+		assert_return_code!(result, 0);
+		let child_addr =
+			Contracts::contract_address(&factory_addr, &callee_hash, &0u32.to_le_bytes(), &[]);
+		// The immediate deployer owns the stake of the new contract
+		assert_eq!(<DelegateInfo<Test>>::get(&child_addr).unwrap().owner(), factory_addr);
+		// The root owner is the account controlling the factory
+		assert_eq!(Pocs::stake_owner(&child_addr), Ok(ALICE));
+		assert_eq!(Pocs::stake_owner(&factory_addr), Ok(ALICE));
+		// Accounts without stake can not be resolved
+		assert_err!(Pocs::stake_owner(&BOB), Error::<Test>::NoStakeExists);
+		// Call the factory in different blocks to reach minimum reputation
+		for block in 2..=3u32 {
+			initialize_block(block.into());
+			let input: Vec<u8> =
+				callee_hash.as_ref().iter().chain(&block.to_le_bytes()).cloned().collect();
+			builder::bare_call(factory_addr.clone()).data(input).build_and_unwrap_result();
+		}
+		// Hand the factory's stake to its own child, closing an ownership cycle
+		assert_ok!(Pocs::update_owner(
+			RuntimeOrigin::signed(ALICE),
+			factory_addr.clone(),
+			child_addr.clone()
+		));
+		assert_err!(Pocs::stake_owner(&child_addr), Error::<Test>::StakeOwnerCycle);
+	});
+}
+
+#[test]
+fn pocs_stake_owner_is_origin_when_configured() {
+	// Gets wasm binaries of the factory contract and the deployed contract
+	let (factory_wasm, _) = compile_module::<Test>("instantiate_return_code").unwrap();
+	let (callee_wasm, callee_hash) = compile_module::<Test>("ok_trap_revert").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(50).build_and_execute(|| {
+		// Assign stake ownership to the transaction origin
+		StakeOwnerIsOrigin::set(true);
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&BOB, 1_000_000);
+		// ALICE instantiates the factory contract and uploads the code it deploys
+		let factory_addr = builder::bare_instantiate(Code::Upload(factory_wasm))
+			.value(100_000)
+			.build_and_unwrap_account_id();
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			callee_wasm,
+			None,
+			Determinism::Enforced
+		));
+		// BOB makes the factory instantiate a new contract
+		let result = builder::bare_call(factory_addr.clone())
+			.origin(BOB)
+			.data(callee_hash.as_ref().iter().chain(&0u32.to_le_bytes()).cloned().collect())
+			.build_and_unwrap_result();
+		assert_return_code!(result, 0);
+		let child_addr =
+			Contracts::contract_address(&factory_addr, &callee_hash, &0u32.to_le_bytes(), &[]);
+		// The transaction origin owns the stake instead of the factory
+		assert_eq!(<DelegateInfo<Test>>::get(&child_addr).unwrap().owner(), BOB);
+		assert_eq!(Pocs::stake_owner(&child_addr), Ok(BOB));
+	});
+}
+
+#[test]
+fn pocs_stake_owner_falls_back_to_code_owner_for_root_origin() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Assign stake ownership to the transaction origin
+		StakeOwnerIsOrigin::set(true);
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// ALICE uploads the code and instantiates the contract
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		// Drop its stake, as for a contract deployed before its stake was tracked
+		StakeRequest::<Test>::delete(&contract_addr);
+		// Root calls the contract without an account to own the stake
+		assert_ok!(builder::call(contract_addr.clone()).origin(RuntimeOrigin::root()).build());
+		// The uploader of the contract's code owns the stake instead
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().owner(), ALICE);
+	});
+}
+
+#[test]
+fn pocs_try_state_detects_inconsistent_validator_count() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract and call it in different blocks to reach minimum reputation
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		// Delegate the contract keeps the storage consistent
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		assert_ok!(Pocs::do_try_state());
+		// A validator count without matching delegations is detected
+		ValidatorInfoMap::<Test>::insert(&BOB, 2);
+		assert!(Pocs::do_try_state().is_err());
+		ValidatorInfoMap::<Test>::insert(&BOB, 1);
+		// Stake of a contract that isn't alive is detected
+		let contract_info_key = [
+			frame_support::storage::storage_prefix(b"Contracts", b"ContractInfoOf").as_slice(),
+			&Twox64Concat::hash(&contract_addr.encode()),
+		]
+		.concat();
+		let contract_info = unhashed::get_raw(&contract_info_key).unwrap();
+		unhashed::kill(&contract_info_key);
+		assert!(Pocs::do_try_state().is_err());
+		// Restore the contract so the storage is consistent again
+		unhashed::put_raw(&contract_info_key, &contract_info);
+		assert_ok!(Pocs::do_try_state());
+	});
+}
+
+#[test]
+fn pocs_recount_restores_validator_counts() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts, one delegated to its owner and one to BOB
+		let self_delegated_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let delegated_addr = builder::bare_instantiate(Code::Existing(code_hash))
+			.salt(vec![1])
+			.build_and_unwrap_account_id();
+		DelegateRequest::<Test>::seed(
+			&self_delegated_addr,
+			&ALICE,
+			Some(&ALICE),
+			MIN_REPUTATION,
+			0,
+		);
+		DelegateRequest::<Test>::seed(&delegated_addr, &ALICE, Some(&BOB), MIN_REPUTATION, 0);
+		// Counts of the previous counting, which skipped the owner and kept stale validators
+		ValidatorInfoMap::<Test>::remove(&ALICE);
+		ValidatorInfoMap::<Test>::insert(&CHARLIE, 2);
+		assert!(Pocs::do_try_state().is_err());
+		// The counts are restored from the delegations
+		assert_eq!(<ValidateRequest<Test>>::recount(), (4, 4));
+		assert_eq!(<ValidateRequest<Test>>::get(&ALICE).unwrap(), 1);
+		assert_eq!(<ValidateRequest<Test>>::get(&BOB).unwrap(), 1);
+		assert_err!(<ValidateRequest<Test>>::get(&CHARLIE), Error::<Test>::NoValidatorFound);
+		assert_ok!(Pocs::do_try_state());
+	});
+}
+
+#[test]
+fn pocs_owner_is_counted_as_validator_of_own_contract() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract and call it in different blocks to reach minimum reputation
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		// Delegate to BOB and then to the owner itself
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		assert_eq!(<ValidateRequest<Test>>::get(&BOB).unwrap(), 1);
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), ALICE));
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(), Some(ALICE));
+		assert_err!(<ValidateRequest<Test>>::get(&BOB), Error::<Test>::NoValidatorFound);
+		assert_eq!(<ValidateRequest<Test>>::get(&ALICE).unwrap(), 1);
+		assert_ok!(Pocs::do_try_state());
+		// Handing the contract to BOB keeps its delegation
+		assert_ok!(Pocs::update_owner(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(), Some(ALICE));
+		assert_eq!(<ValidateRequest<Test>>::get(&ALICE).unwrap(), 1);
+		// Delegate to CHARLIE and hand the contract to its validator
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(BOB), contract_addr.clone(), CHARLIE));
+		assert_err!(<ValidateRequest<Test>>::get(&ALICE), Error::<Test>::NoValidatorFound);
+		assert_ok!(Pocs::update_owner(RuntimeOrigin::signed(BOB), contract_addr.clone(), CHARLIE));
+		assert_eq!(<ValidateRequest<Test>>::get(&CHARLIE).unwrap(), 1);
+		assert_ok!(Pocs::do_try_state());
+	});
+}
+
+#[test]
+fn pocs_undelegate_returns_contract_to_owner() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract and call it in different blocks to reach minimum reputation
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		// Delegate to BOB and earn stake score
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		initialize_block(4);
+		builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		assert!(
+			<StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score() > INITIAL_STAKE_SCORE
+		);
+		// Undelegate the contract
+		initialize_block(5);
+		assert_ok!(Pocs::undelegate(RuntimeOrigin::signed(ALICE), contract_addr.clone()));
+		let delegate_info = <DelegateInfo<Test>>::get(&contract_addr).unwrap();
+		assert_eq!(delegate_info.delegate_to(), None);
+		assert_eq!(delegate_info.delegate_at(), 5);
+		assert_eq!(delegate_info.owner(), ALICE);
+		// Stake score is reset while the reputation is retained
+		let stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		assert_eq!(stake_info.stake_score(), INITIAL_STAKE_SCORE);
+		assert_eq!(stake_info.reputation(), MIN_REPUTATION + 1);
+		// The contract no longer counts towards its validator
+		assert_err!(<ValidateRequest<Test>>::get(&BOB), Error::<Test>::NoValidatorFound);
+		assert_eq!(
+			System::events()
+				.iter()
+				.filter_map(|record| match &record.event {
+					RuntimeEvent::Pocs(event) => Some(event.clone()),
+					_ => None,
+				})
+				.collect::<Vec<_>>(),
+			vec![
+				crate::Event::Undelegated { contract: contract_addr.clone(), delegate_to: BOB },
+				crate::Event::ValidateInfo {
+					validator: BOB,
+					num_delegates: 0,
+					can_validate: false
+				},
+			],
+		);
+		// An undelegated contract earns no stake score
+		initialize_block(6);
+		builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		assert_eq!(
+			<StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score(),
+			INITIAL_STAKE_SCORE
+		);
+		assert_ok!(Pocs::do_try_state());
+	});
+}
+
+#[test]
+fn pocs_cannot_undelegate_undelegated_contract() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		// A contract that was never delegated can't be undelegated
+		assert_err!(
+			Pocs::undelegate(RuntimeOrigin::signed(ALICE), contract_addr.clone()),
+			Error::<Test>::NotDelegated
+		);
+		// Accounts without stake can't be undelegated
+		assert_err!(
+			Pocs::undelegate(RuntimeOrigin::signed(ALICE), CHARLIE),
+			Error::<Test>::NoStakeExists
+		);
+		// Call the contract in different blocks to reach minimum reputation and delegate it
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// Only the owner may undelegate the contract, even if it is the validator
+		assert_err!(
+			Pocs::undelegate(RuntimeOrigin::signed(BOB), contract_addr.clone()),
+			Error::<Test>::InvalidContractOwner
+		);
+		// Undelegating twice fails
+		assert_ok!(Pocs::undelegate(RuntimeOrigin::signed(ALICE), contract_addr.clone()));
+		assert_err!(
+			Pocs::undelegate(RuntimeOrigin::signed(ALICE), contract_addr.clone()),
+			Error::<Test>::NotDelegated
+		);
+	});
+}
+
+#[test]
+fn pocs_genesis_config_seeds_stake_and_validators() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Ten contracts deployed by ALICE and one by CHARLIE
+	let contracts: Vec<_> = (0..11u8)
+		.map(|i| {
+			let deployer = if i < 10 { ALICE } else { CHARLIE };
+			(deployer, wasm.clone(), vec![], vec![i], 0)
+		})
+		.collect();
+	let addrs: Vec<_> = contracts
+		.iter()
+		.map(|(deployer, _, data, salt, _)| {
+			Contracts::contract_address(deployer, &code_hash, data, salt)
+		})
+		.collect();
+	// ALICE's contracts are delegated to BOB and CHARLIE's contract stays undelegated
+	let mut stakes: Vec<_> =
+		addrs[..10].iter().map(|addr| (addr.clone(), ALICE, Some(BOB), 3, 1_000)).collect();
+	stakes.push((addrs[10].clone(), CHARLIE, None, 0, 0));
+	// Test Execution Interface under a closure
+	ExtBuilder::default()
+		.with_balances(vec![(ALICE, 1_000_000), (CHARLIE, 1_000_000)])
+		.with_contracts(contracts)
+		.with_stakes(stakes)
+		.build_and_execute(|| {
+			// Contracts are deployed at genesis
+			for addr in addrs.iter() {
+				assert!(Contracts::code_hash(addr).is_some());
+			}
+			// Stake and delegate information is seeded
+			let delegate_info = <DelegateInfo<Test>>::get(&addrs[0]).unwrap();
+			assert_eq!(delegate_info.owner(), ALICE);
+			assert_eq!(delegate_info.delegate_to(), Some(BOB));
+			let stake_info = <StakeInfo<Test>>::get(&addrs[0]).unwrap();
+			assert_eq!(stake_info.reputation(), 3);
+			assert_eq!(stake_info.stake_score(), 1_000);
+			// Reputation never drops below the reputation factor
+			let stake_info = <StakeInfo<Test>>::get(&addrs[10]).unwrap();
+			assert_eq!(stake_info.reputation(), REPUTATION_FACTOR);
+			// Validator counts are derived from the delegations
+			assert_eq!(<ValidateRequest<Test>>::get(&BOB).unwrap(), 10);
+			assert_err!(<ValidateRequest<Test>>::get(&CHARLIE), Error::<Test>::NoValidatorFound);
+			assert_ok!(Pocs::validate(RuntimeOrigin::signed(BOB)));
+			// Genesis contracts are callable
+			initialize_block(2);
+			builder::bare_call(addrs[0].clone()).build_and_unwrap_result();
+		});
+}
+
+#[test]
+fn pocs_genesis_contracts_are_staked_by_deployer() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	let addr = Contracts::contract_address(&BOB, &code_hash, &[], &[]);
+	// Test Execution Interface under a closure
+	ExtBuilder::default()
+		.with_balances(vec![(BOB, 1_000_000)])
+		.with_contracts(vec![(BOB, wasm, vec![], vec![], 1_000)])
+		.build_and_execute(|| {
+			// The code is uploaded by and the contract staked for its deployer
+			assert_eq!(Contracts::code_owner(&code_hash), Some(BOB));
+			assert_eq!(<DelegateInfo<Test>>::get(&addr).unwrap().owner(), BOB);
+			assert_eq!(<StakeInfo<Test>>::get(&addr).unwrap().reputation(), REPUTATION_FACTOR);
+		});
+}
+
+#[test]
+#[should_panic(expected = "names no contract")]
+fn pocs_genesis_stake_requires_contract() {
+	ExtBuilder::default()
+		.with_stakes(vec![(AccountId32::new([100; 32]), ALICE, Some(BOB), 3, 0)])
+		.build();
+}
+
+#[test]
+fn pocs_stake_summary_per_transaction() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Summarize stake updates once an execution has finished
+		EventMode::set(StakeEventMode::PerTransaction);
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract and call it in different blocks to reach minimum reputation
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		// Delegate the contract to earn stake score
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// Call the contract twice within the same block
+		initialize_block(4);
+		builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		let stake_score = <StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score();
+		// Each call is summarized on its own instead of emitting Staked events
+		let summaries: Vec<_> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::Pocs(crate::Event::Staked { .. }) =>
+					panic!("Staked event emitted outside of per frame mode"),
+				RuntimeEvent::Pocs(crate::Event::StakeSummary { contracts, frames, gas }) =>
+					Some((contracts, frames, gas)),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(summaries.len(), 2);
+		assert!(summaries.iter().all(|(contracts, frames, gas)| {
+			contracts.len() == 1 && contracts[0].0 == contract_addr && *frames == 1 && *gas > 0
+		}));
+		assert_eq!(summaries[1].0[0].1, stake_score);
+		// Nothing is left pending once the execution has finished
+		assert_eq!(PendingStakeMap::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn pocs_stake_summary_per_transaction_is_bounded() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract and call it in different blocks to reach minimum reputation
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		// Delegate the contract to earn stake score
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// Each frame reserves the weight of summarizing its contract
+		let frame = ExecutedFrame::<Test> {
+			origin: Origin::from_account_id(ALICE),
+			caller: Origin::from_account_id(ALICE),
+			contract: contract_addr.clone(),
+			code_hash,
+			entry_point: ExportedFunction::Call,
+			delegated: false,
+			gas_consumed: Weight::zero(),
+		};
+		let per_frame_weight = <Pocs as OnContractExecution<Test>>::frame_weight(&frame);
+		EventMode::set(StakeEventMode::PerTransaction);
+		assert_eq!(
+			<Pocs as OnContractExecution<Test>>::frame_weight(&frame),
+			per_frame_weight
+				.saturating_add(<Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(<() as WeightInfo>::summarize(1))
+		);
+		// Stake update of another contract left pending, e.g. by the per block mode
+		let pending_addr = AccountId32::new([100; 32]);
+		PendingStakeMap::<Test>::insert(&pending_addr, PendingStake::default().add(&1));
+		// A call with a single frame only summarizes a single contract
+		initialize_block(4);
+		builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		assert_eq!(PendingStakeMap::<Test>::iter().count(), 1);
+		assert_eq!(PendingStakeCount::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn pocs_stake_summary_per_block() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Summarize stake updates when the block is finalized
+		EventMode::set(StakeEventMode::PerBlock);
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract and call it in different blocks to reach minimum reputation
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		// Delegate the contract to earn stake score
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// Call the contract twice within the same block
+		initialize_block(4);
+		builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		// Stake updates are pending until the block is finalized
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Pocs(crate::Event::Staked { .. } | crate::Event::StakeSummary { .. })
+		)));
+		let pending = PendingStakeMap::<Test>::get(&contract_addr).unwrap();
+		assert_eq!(pending.frames(), 2);
+		// A single summary covers both calls
+		Pocs::on_finalize(4);
+		let stake_score = <StakeInfo<Test>>::get(&contract_addr).unwrap().stake_score();
+		assert!(System::events().iter().any(|record| matches!(
+			&record.event,
+			RuntimeEvent::Pocs(crate::Event::StakeSummary { contracts, frames, gas })
+				if *contracts == vec![(contract_addr.clone(), stake_score)] &&
+					*frames == 2 && *gas == pending.gas()
+		)));
+		assert_eq!(PendingStakeMap::<Test>::iter().count(), 0);
+		// No summary is deposited for a block without stake updates
+		initialize_block(5);
+		Pocs::on_finalize(5);
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn pocs_stake_summary_per_block_is_bounded() {
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Summarize the stake updates of a single contract per block
+		EventMode::set(StakeEventMode::PerBlock);
+		MaxStakeSummary::set(1);
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate two Contracts and call them in different blocks to reach minimum reputation
+		let contracts: Vec<_> = (0u8..2)
+			.map(|salt| {
+				builder::bare_instantiate(Code::Upload(wasm.clone()))
+					.salt(vec![salt])
+					.build_and_unwrap_account_id()
+			})
+			.collect();
+		for block in 2..=3 {
+			initialize_block(block);
+			for contract_addr in &contracts {
+				builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+			}
+		}
+		// Delegate both contracts and call them within the same block
+		initialize_block(4);
+		for contract_addr in &contracts {
+			assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		// The weight of the bounded summary is reserved upfront
+		assert_eq!(Pocs::on_initialize(4), <() as WeightInfo>::summarize(1));
+		let summarized = |contracts: &[AccountId32]| {
+			System::events().iter().any(|record| {
+				matches!(
+					&record.event,
+					RuntimeEvent::Pocs(crate::Event::StakeSummary { contracts: summary, .. })
+						if summary.iter().map(|(contract, _)| contract).eq(contracts.iter())
+				)
+			})
+		};
+		// Each block summarizes a single contract, the other one remains pending
+		Pocs::on_finalize(4);
+		let pending: Vec<_> = PendingStakeMap::<Test>::iter_keys().collect();
+		assert_eq!(pending.len(), 1);
+		let first: Vec<_> = contracts.iter().filter(|c| !pending.contains(c)).cloned().collect();
+		assert!(summarized(&first));
+		initialize_block(5);
+		Pocs::on_finalize(5);
+		assert!(summarized(&pending));
+		assert_eq!(PendingStakeMap::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn pocs_chain_extension_fetch_runs_out_of_gas() {
+	// Gets wasm binary of a contract forwarding its input to a chain extension
+	let (wasm, _) = compile_module::<Test>("pocs_chain_extension").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		// Fetch the reputation of the contract itself with enough gas
+		let input = chain_extension_input(1200, 1003, &contract_addr);
+		let result = builder::bare_call(contract_addr.clone()).data(input.clone()).build();
+		let output = result.result.clone().unwrap();
+		let (ret_code, mut output) = chain_extension_output(&output.data);
+		assert_eq!(ret_code, PocsReturnCode::Success as u32);
+		assert_eq!(u32::decode(&mut output).unwrap(), REPUTATION_FACTOR);
+		// The benchmarked weight is part of the consumed gas
+		let charged = <() as WeightInfo>::fetch_reputation();
+		assert!(result.gas_consumed.all_gte(charged));
+		// Without the gas for the charged weight the caller runs out of gas
+		let result = builder::bare_call(contract_addr.clone())
+			.gas_limit(result.gas_consumed.saturating_sub(charged))
+			.data(input)
+			.build();
+		assert_err!(result.result, pallet_contracts::Error::<Test>::OutOfGas);
+	});
+}
+
+#[test]
+fn pocs_chain_extension_delegate_runs_out_of_gas() {
+	// Gets wasm binary of a contract forwarding its input to a chain extension
+	let (wasm, _) = compile_module::<Test>("pocs_chain_extension").unwrap();
+	// Gets dummy wasm binary for the contract owned by the calling contract
+	let (dummy_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts
+		let caller_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(dummy_wasm)).build_and_unwrap_account_id();
+		// Hand the contract over to the calling contract with minimum reputation
+		DelegateRequest::<Test>::seed(&contract_addr, &caller_addr, None, MIN_REPUTATION, 0);
+		// Measure the gas of delegating the contract to BOB without persisting it
+		let input = chain_extension_input(1300, 1005, (&contract_addr, &BOB));
+		let gas_consumed = with_transaction(|| {
+			let result = builder::bare_call(caller_addr.clone()).data(input.clone()).build();
+			assert_ok!(result.result);
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(result.gas_consumed))
+		})
+		.unwrap();
+		// Without the gas for the charged weight the caller runs out of gas
+		let charged = <() as WeightInfo>::delegate();
+		let result = builder::bare_call(caller_addr.clone())
+			.gas_limit(gas_consumed.saturating_sub(charged))
+			.data(input.clone())
+			.build();
+		assert_err!(result.result, pallet_contracts::Error::<Test>::OutOfGas);
+		// The contract was not delegated
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(), None);
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), None);
+		// With enough gas the contract is delegated
+		assert_ok!(builder::bare_call(caller_addr.clone()).data(input).build().result);
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(), Some(BOB));
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), Some(1));
+	});
+}
+
+#[test]
+fn pocs_chain_extension_rejected_delegate_is_refunded() {
+	// Gets wasm binary of a contract forwarding its input to a chain extension
+	let (wasm, _) = compile_module::<Test>("pocs_chain_extension").unwrap();
+	// Gets dummy wasm binary for the contract owned by the calling contract
+	let (dummy_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts
+		let caller_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(dummy_wasm)).build_and_unwrap_account_id();
+		// Hand the contract over to the calling contract without minimum reputation
+		DelegateRequest::<Test>::seed(&contract_addr, &caller_addr, None, REPUTATION_FACTOR, 0);
+		// Delegating the contract is rejected
+		let input = chain_extension_input(1300, 1005, (&contract_addr, &BOB));
+		let result = builder::bare_call(caller_addr.clone()).data(input).build();
+		let output = result.result.clone().unwrap();
+		assert_eq!(chain_extension_output(&output.data).0, PocsReturnCode::LowReputation as u32);
+		// The weight of the worst case is required upfront, the unused part is refunded before
+		// the remaining execution is charged, which may use up the refunded ref time again
+		let refund = <() as WeightInfo>::delegate()
+			.saturating_sub(<() as WeightInfo>::delegate_request_rejected());
+		assert!(result.gas_required.any_gt(result.gas_consumed));
+		assert!(result.gas_consumed.saturating_add(refund).all_gte(result.gas_required));
+	});
+}
+
+#[test]
+fn pocs_chain_extension_returns_error_codes() {
+	// Gets wasm binary of a contract forwarding its input to a chain extension
+	let (wasm, _) = compile_module::<Test>("pocs_chain_extension").unwrap();
+	// Gets dummy wasm binary for the contract owned by the calling contract
+	let (dummy_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts
+		let caller_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(dummy_wasm)).build_and_unwrap_account_id();
+		let call = |input: Vec<u8>| {
+			let output =
+				builder::bare_call(caller_addr.clone()).data(input).build_and_unwrap_result();
+			assert!(!output.did_revert());
+			chain_extension_output(&output.data).0
+		};
+		// Fetching the stake of an account without stake
+		for func_id in 1000..=1004 {
+			assert_eq!(
+				call(chain_extension_input(1200, func_id, &BOB)),
+				PocsReturnCode::NoStakeExists as u32,
+			);
+		}
+		// Delegating a contract owned by ALICE
+		assert_eq!(
+			call(chain_extension_input(1300, 1005, (&contract_addr, &BOB))),
+			PocsReturnCode::InvalidContractOwner as u32,
+		);
+		// Hand the contract over to the calling contract with minimum reputation
+		DelegateRequest::<Test>::seed(&contract_addr, &caller_addr, Some(&BOB), MIN_REPUTATION, 0);
+		// Delegating the contract to its current validator
+		assert_eq!(
+			call(chain_extension_input(1300, 1005, (&contract_addr, &BOB))),
+			PocsReturnCode::AlreadyDelegated as u32,
+		);
+		// Handing the contract over to its current owner
+		assert_eq!(
+			call(chain_extension_input(1300, 1006, (&contract_addr, &caller_addr))),
+			PocsReturnCode::AlreadyOwner as u32,
+		);
+		// The contract can handle the error and continue with another request
+		assert_eq!(
+			call(chain_extension_input(1300, 1005, (&contract_addr, &CHARLIE))),
+			PocsReturnCode::Success as u32,
+		);
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(), Some(CHARLIE));
+	});
+}
+
+#[test]
+fn pocs_chain_extension_undelegates_owned_contract() {
+	// Gets wasm binary of a contract forwarding its input to a chain extension
+	let (wasm, _) = compile_module::<Test>("pocs_chain_extension").unwrap();
+	// Gets dummy wasm binary for the contract owned by the calling contract
+	let (dummy_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts
+		let caller_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(dummy_wasm)).build_and_unwrap_account_id();
+		let call = |input: Vec<u8>| {
+			let output =
+				builder::bare_call(caller_addr.clone()).data(input).build_and_unwrap_result();
+			assert!(!output.did_revert());
+			chain_extension_output(&output.data).0
+		};
+		let fetch = |func_id: u16| {
+			let output = builder::bare_call(caller_addr.clone())
+				.data(chain_extension_input(1200, func_id, &contract_addr))
+				.build_and_unwrap_result();
+			let (ret_code, output) = chain_extension_output(&output.data);
+			assert_eq!(ret_code, PocsReturnCode::Success as u32);
+			output.to_vec()
+		};
+		let input = chain_extension_input(1300, 1013, &contract_addr);
+		// Undelegating a contract owned by ALICE
+		assert_eq!(call(input.clone()), PocsReturnCode::InvalidContractOwner as u32);
+		// Hand the contract over to the calling contract, delegated to BOB
+		DelegateRequest::<Test>::seed(&contract_addr, &caller_addr, Some(&BOB), MIN_REPUTATION, 0);
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), Some(1));
+		// The delegate of a delegated contract is fetched either way
+		assert_eq!(AccountId32::decode(&mut &fetch(1000)[..]).unwrap(), BOB);
+		assert_eq!(<Option<AccountId32>>::decode(&mut &fetch(1014)[..]).unwrap(), Some(BOB));
+		// The calling contract undelegates the contract
+		assert_eq!(call(input.clone()), PocsReturnCode::Success as u32);
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(), None);
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), None);
+		// The delegate of an undelegated contract is still fetched as its owner
+		assert_eq!(AccountId32::decode(&mut &fetch(1000)[..]).unwrap(), caller_addr);
+		// Fetching the delegate as an option yields `None`
+		assert_eq!(<Option<AccountId32>>::decode(&mut &fetch(1014)[..]).unwrap(), None);
+		// Undelegating it again is rejected
+		assert_eq!(call(input), PocsReturnCode::NotDelegated as u32);
+	});
+}
+
+#[test]
+fn pocs_return_codes_are_stable() {
+	// Codes are part of the contract ABI and must never change
+	let codes = [
+		(Error::<Test>::NoStakeExists, PocsReturnCode::NoStakeExists, 1),
+		(Error::<Test>::AlreadyDelegated, PocsReturnCode::AlreadyDelegated, 2),
+		(Error::<Test>::LowReputation, PocsReturnCode::LowReputation, 3),
+		(Error::<Test>::InvalidContractOwner, PocsReturnCode::InvalidContractOwner, 4),
+		(Error::<Test>::AlreadyOwner, PocsReturnCode::AlreadyOwner, 5),
+		(Error::<Test>::InsufficientDelegates, PocsReturnCode::InsufficientDelegates, 6),
+		(Error::<Test>::NoValidatorFound, PocsReturnCode::NoValidatorFound, 7),
+		(Error::<Test>::StakeOwnerCycle, PocsReturnCode::StakeOwnerCycle, 8),
+		(Error::<Test>::StakeOwnerDepthExceeded, PocsReturnCode::StakeOwnerDepthExceeded, 9),
+		(Error::<Test>::NotDelegated, PocsReturnCode::NotDelegated, 10),
+	];
+	for (error, code, value) in codes {
+		assert_eq!(PocsReturnCode::from_error::<Test>(&error.into()), Some(code));
+		assert_eq!(u32::from(code), value);
+	}
+	assert_eq!(u32::from(PocsReturnCode::Success), 0);
+	// Errors of other pallets trap the calling contract instead
+	assert_eq!(
+		PocsReturnCode::from_error::<Test>(&pallet_contracts::Error::<Test>::OutOfGas.into()),
+		None,
+	);
+}
+
+#[test]
+fn pocs_return_codes_match_contract_bindings() {
+	use pallet_contracts_uapi::pocs::PocsError;
+	// Contracts decode every code to the error of the same name
+	let codes = [
+		(PocsReturnCode::NoStakeExists, PocsError::NoStakeExists),
+		(PocsReturnCode::AlreadyDelegated, PocsError::AlreadyDelegated),
+		(PocsReturnCode::LowReputation, PocsError::LowReputation),
+		(PocsReturnCode::InvalidContractOwner, PocsError::InvalidContractOwner),
+		(PocsReturnCode::AlreadyOwner, PocsError::AlreadyOwner),
+		(PocsReturnCode::InsufficientDelegates, PocsError::InsufficientDelegates),
+		(PocsReturnCode::NoValidatorFound, PocsError::NoValidatorFound),
+		(PocsReturnCode::StakeOwnerCycle, PocsError::StakeOwnerCycle),
+		(PocsReturnCode::StakeOwnerDepthExceeded, PocsError::StakeOwnerDepthExceeded),
+	];
+	for (code, error) in codes {
+		assert_eq!(PocsError::from_code(code.into()), Err(error));
+		assert_eq!(u32::from(error), u32::from(code));
+	}
+	assert_eq!(PocsError::from_code(PocsReturnCode::Success.into()), Ok(()));
+}
+
+#[test]
+fn pocs_chain_extension_queries_whole_stake_and_validators() {
+	// Gets wasm binary of a contract forwarding its input to a chain extension
+	let (wasm, _) = compile_module::<Test>("pocs_chain_extension").unwrap();
+	// Gets dummy wasm binary for the contracts delegated to a validator
+	let (dummy_wasm, dummy_code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 10_000_000);
+		// Instantiate Contracts
+		let caller_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(dummy_wasm)).build_and_unwrap_account_id();
+		// Delegate the contract to BOB with some stake
+		DelegateRequest::<Test>::seed(&contract_addr, &ALICE, Some(&BOB), MIN_REPUTATION, 42);
+		let call = |input: Vec<u8>| {
+			let output =
+				builder::bare_call(caller_addr.clone()).data(input).build_and_unwrap_result();
+			let (ret_code, output) = chain_extension_output(&output.data);
+			(ret_code, output.to_vec())
+		};
+		// Whole StakeInfo and DelegateInfo in one call each
+		let (ret_code, output) = call(chain_extension_input(1200, 1007, &contract_addr));
+		assert_eq!(ret_code, PocsReturnCode::Success as u32);
+		let stake_info = <StakeInfo<Test>>::decode(&mut &output[..]).unwrap();
+		assert_eq!(stake_info, <StakeInfo<Test>>::get(&contract_addr).unwrap());
+		assert_eq!(stake_info.stake_score(), 42);
+		let (ret_code, output) = call(chain_extension_input(1200, 1008, &contract_addr));
+		assert_eq!(ret_code, PocsReturnCode::Success as u32);
+		let delegate_info = <DelegateInfo<Test>>::decode(&mut &output[..]).unwrap();
+		assert_eq!(delegate_info, <DelegateInfo<Test>>::get(&contract_addr).unwrap());
+		assert_eq!(delegate_info.delegate_to(), Some(BOB));
+		// Delegate count and eligibility of a validator
+		assert_eq!(call(chain_extension_input(1200, 1009, &BOB)), (0, 1u32.encode()));
+		assert_eq!(call(chain_extension_input(1200, 1010, &BOB)), (0, false.encode()));
+		assert_eq!(
+			call(chain_extension_input(1200, 1009, &CHARLIE)).0,
+			PocsReturnCode::NoValidatorFound as u32,
+		);
+		assert_eq!(call(chain_extension_input(1200, 1010, &CHARLIE)), (0, false.encode()));
+		// BOB becomes eligible with the minimum number of delegates
+		for salt in 1..MIN_DELEGATES {
+			let contract_addr = builder::bare_instantiate(Code::Existing(dummy_code_hash))
+				.salt(salt.encode())
+				.build_and_unwrap_account_id();
+			DelegateRequest::<Test>::seed(&contract_addr, &ALICE, Some(&BOB), MIN_REPUTATION, 0);
+		}
+		assert_eq!(call(chain_extension_input(1200, 1009, &BOB)), (0, MIN_DELEGATES.encode()));
+		assert_eq!(call(chain_extension_input(1200, 1010, &BOB)), (0, true.encode()));
+		// The effective parameters take no input
+		let (ret_code, output) = call(chain_extension_input(1200, 1011, ()));
+		assert_eq!(ret_code, PocsReturnCode::Success as u32);
+		let params = <PocsParams<Test>>::decode(&mut &output[..]).unwrap();
+		assert_eq!(params, <PocsParams<Test>>::get());
+		assert_eq!(params.min_reputation, MIN_REPUTATION);
+		assert_eq!(params.min_delegates, MIN_DELEGATES);
+		assert_eq!(params.failure_window, FailureWindow::get());
+		assert_eq!(params.stake_event_mode, StakeEventMode::PerFrame);
+	});
+}
+
+#[test]
+fn pocs_stake_reader_reads_stake_of_contracts() {
+	// Gets wasm binary of a contract reading stake through the chain extension
+	let (wasm, _) = compile_module::<Test>("pocs_stake_reader").unwrap();
+	// Gets dummy wasm binary for the contract whose stake is read
+	let (dummy_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts
+		let reader_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(dummy_wasm)).build_and_unwrap_account_id();
+		// The reader receives the whole stake of the contract
+		let output = builder::bare_call(reader_addr.clone())
+			.data(contract_addr.encode())
+			.build_and_unwrap_result();
+		assert!(!output.did_revert());
+		let (stake_info, delegate_info) =
+			<(StakeInfo<Test>, DelegateInfo<Test>)>::decode(&mut &output.data[..]).unwrap();
+		assert_eq!(stake_info, <StakeInfo<Test>>::get(&contract_addr).unwrap());
+		assert_eq!(delegate_info, <DelegateInfo<Test>>::get(&contract_addr).unwrap());
+		assert_eq!(delegate_info.owner(), ALICE);
+		// The reader handles accounts without stake
+		let output =
+			builder::bare_call(reader_addr.clone()).data(BOB.encode()).build_and_unwrap_result();
+		assert!(output.did_revert());
+		assert_return_code!(output, PocsReturnCode::NoStakeExists);
+	});
+}
+
+#[test]
+fn pocs_factory_delegates_its_children() {
+	// Gets wasm binary of a contract instantiating and delegating child contracts
+	let (wasm, _) = compile_module::<Test>("pocs_factory").unwrap();
+	// Gets dummy wasm binary for the child contracts
+	let (dummy_wasm, dummy_code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate two factories funding their children
+		let factory_addr = builder::bare_instantiate(Code::Upload(wasm))
+			.value(100_000)
+			.build_and_unwrap_account_id();
+		let other_factory_addr =
+			builder::bare_instantiate(Code::Existing(Contracts::code_hash(&factory_addr).unwrap()))
+				.value(100_000)
+				.salt(vec![1])
+				.build_and_unwrap_account_id();
+		// Upload the code of the children
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			dummy_wasm,
+			None,
+			Determinism::Enforced,
+		));
+		let factory_call = |factory: &AccountId32, input: Vec<u8>| {
+			builder::bare_call(factory.clone()).data(input).build_and_unwrap_result()
+		};
+		// The factory instantiates a child and owns its stake
+		let output = factory_call(&factory_addr, (0u8, dummy_code_hash, [1u8; 32]).encode());
+		let child_addr = AccountId32::decode(&mut &output.data[..]).unwrap();
+		assert_eq!(<DelegateInfo<Test>>::get(&child_addr).unwrap().owner(), factory_addr);
+		// The child is rejected without minimum reputation
+		let output = factory_call(&factory_addr, (1u8, &child_addr, &BOB).encode());
+		assert_return_code!(output, PocsReturnCode::LowReputation);
+		// Call the child in different blocks to reach minimum reputation
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(child_addr.clone()).build_and_unwrap_result();
+		}
+		// Neither the deployer of the factory nor another contract may delegate the child
+		assert_err!(
+			Pocs::delegate(RuntimeOrigin::signed(ALICE), child_addr.clone(), BOB),
+			Error::<Test>::InvalidContractOwner
+		);
+		let output = factory_call(&other_factory_addr, (1u8, &child_addr, &BOB).encode());
+		assert_return_code!(output, PocsReturnCode::InvalidContractOwner);
+		// The factory delegates its child
+		let output = factory_call(&factory_addr, (1u8, &child_addr, &BOB).encode());
+		assert_return_code!(output, PocsReturnCode::Success);
+		assert_eq!(<DelegateInfo<Test>>::get(&child_addr).unwrap().delegate_to(), Some(BOB));
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), Some(1));
+		assert!(System::events().iter().any(|record| record.event ==
+			RuntimeEvent::Pocs(crate::Event::Delegated {
+				contract: child_addr.clone(),
+				delegate_to: BOB,
+			})));
+		// Delegating to the same validator again is rejected
+		let output = factory_call(&factory_addr, (1u8, &child_addr, &BOB).encode());
+		assert_return_code!(output, PocsReturnCode::AlreadyDelegated);
+		// The factory hands the child over to ALICE, who may delegate it from then on
+		let output = factory_call(&factory_addr, (2u8, &child_addr, &ALICE).encode());
+		assert_return_code!(output, PocsReturnCode::Success);
+		assert_eq!(<DelegateInfo<Test>>::get(&child_addr).unwrap().owner(), ALICE);
+		let output = factory_call(&factory_addr, (1u8, &child_addr, &CHARLIE).encode());
+		assert_return_code!(output, PocsReturnCode::InvalidContractOwner);
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), child_addr.clone(), CHARLIE));
+		assert_eq!(<DelegateInfo<Test>>::get(&child_addr).unwrap().delegate_to(), Some(CHARLIE));
+	});
+}
+
+#[test]
+fn pocs_contract_validates_itself() {
+	// Gets wasm binary of a contract standing as validator, e.g., a staking pool
+	let (wasm, _) = compile_module::<Test>("pocs_factory").unwrap();
+	// Gets dummy wasm binary for the contracts delegated to it
+	let (dummy_wasm, dummy_code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts
+		let pool_addr = builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		assert_ok!(Contracts::upload_code(
+			RuntimeOrigin::signed(ALICE),
+			dummy_wasm,
+			None,
+			Determinism::Enforced,
+		));
+		let contracts: Vec<AccountId32> = (0..MIN_DELEGATES as u8)
+			.map(|salt| {
+				builder::bare_instantiate(Code::Existing(dummy_code_hash))
+					.salt(vec![salt])
+					.build_and_unwrap_account_id()
+			})
+			.collect();
+		let validate = || {
+			builder::bare_call(pool_addr.clone())
+				.data((3u8, [0u8; 32], [0u8; 32]).encode())
+				.build_and_unwrap_result()
+		};
+		// The pool is no validator without delegates
+		assert_return_code!(validate(), PocsReturnCode::NoValidatorFound);
+		// Call the contracts in different blocks to reach minimum reputation
+		for block in 2..=3 {
+			initialize_block(block);
+			for contract_addr in &contracts {
+				builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+			}
+		}
+		// ALICE delegates her contracts to the pool
+		let (last_contract, contracts) = contracts.split_last().unwrap();
+		for contract_addr in contracts {
+			assert_ok!(Pocs::delegate(
+				RuntimeOrigin::signed(ALICE),
+				contract_addr.clone(),
+				pool_addr.clone(),
+			));
+		}
+		assert_return_code!(validate(), PocsReturnCode::InsufficientDelegates);
+		// The pool validates with enough delegates
+		assert_ok!(Pocs::delegate(
+			RuntimeOrigin::signed(ALICE),
+			last_contract.clone(),
+			pool_addr.clone(),
+		));
+		assert_eq!(ValidatorInfoMap::<Test>::get(&pool_addr), Some(MIN_DELEGATES));
+		assert_return_code!(validate(), PocsReturnCode::Success);
+	});
+}
+
+#[test]
+fn pocs_contract_reads_its_frame_stake() {
+	// Gets wasm binary of a contract reading the stake of its current frame
+	let (wasm, _) = compile_module::<Test>("pocs_frame_stake").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contract
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let frame_stake = || {
+			let output = builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+			assert!(!output.did_revert());
+			<(u64, u128)>::decode(&mut &output.data[..]).unwrap()
+		};
+		// An undelegated contract earns no stake
+		let (gas_consumed, stake_score) = frame_stake();
+		assert!(gas_consumed > 0);
+		assert_eq!(stake_score, 0);
+		// Call the contract in different blocks to reach minimum reputation and delegate it
+		for block in 2..=3 {
+			initialize_block(block);
+			frame_stake();
+		}
+		assert_ok!(Pocs::delegate(RuntimeOrigin::signed(ALICE), contract_addr.clone(), BOB));
+		// The first frame within a block earns its gas weighted by the reputation
+		initialize_block(4);
+		let stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		let (gas_consumed, stake_score) = frame_stake();
+		assert_eq!(stake_score, gas_consumed as u128 * stake_info.reputation() as u128);
+		assert_eq!(stake_score, stake_info.earned(&gas_consumed));
+		// The frame earns the stake of the gas consumed up to its end
+		let new_stake_info = <StakeInfo<Test>>::get(&contract_addr).unwrap();
+		assert!(new_stake_info.stake_score() - stake_info.stake_score() > stake_score);
+		// Later frames within the same block earn their gas only
+		let (gas_consumed, stake_score) = frame_stake();
+		assert_eq!(stake_score, gas_consumed as u128);
+	});
+}
+
+#[test]
+fn pocs_host_functions_read_and_delegate_stake() {
+	// Gets wasm binary of a contract using the PoCS host functions
+	let (wasm, _) = compile_module::<Test>("pocs_host_fns").unwrap();
+	// Gets dummy wasm binary for the contract whose stake is read and delegated
+	let (dummy_wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts
+		let caller_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let contract_addr =
+			builder::bare_instantiate(Code::Upload(dummy_wasm)).build_and_unwrap_account_id();
+		let host_call = |selector: u8, contract: &AccountId32, delegate_to: &AccountId32| {
+			builder::bare_call(caller_addr.clone())
+				.data((selector, contract, delegate_to).encode())
+				.build_and_unwrap_result()
+		};
+		// The caller receives the whole stake of the contract
+		let output = host_call(0, &contract_addr, &BOB);
+		assert!(!output.did_revert());
+		let (stake_info, delegate_info) =
+			<(StakeInfo<Test>, DelegateInfo<Test>)>::decode(&mut &output.data[..]).unwrap();
+		assert_eq!(stake_info, <StakeInfo<Test>>::get(&contract_addr).unwrap());
+		assert_eq!(delegate_info, <DelegateInfo<Test>>::get(&contract_addr).unwrap());
+		// Accounts without stake are reported with the same code as the chain extension
+		let output = host_call(0, &BOB, &BOB);
+		assert!(output.did_revert());
+		assert_return_code!(output, PocsReturnCode::NoStakeExists);
+		// The caller may not delegate a contract owned by ALICE
+		let output = host_call(1, &contract_addr, &BOB);
+		assert_return_code!(output, PocsReturnCode::InvalidContractOwner);
+		// Call the contract in different blocks to reach minimum reputation
+		for block in 2..=3 {
+			initialize_block(block);
+			builder::bare_call(contract_addr.clone()).build_and_unwrap_result();
+		}
+		// ALICE hands the contract over to the caller
+		assert_ok!(Pocs::update_owner(
+			RuntimeOrigin::signed(ALICE),
+			contract_addr.clone(),
+			caller_addr.clone(),
+		));
+		// The caller delegates the contract
+		let output = host_call(1, &contract_addr, &BOB);
+		assert_return_code!(output, PocsReturnCode::Success);
+		assert_eq!(<DelegateInfo<Test>>::get(&contract_addr).unwrap().delegate_to(), Some(BOB));
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), Some(1));
+		let output = host_call(1, &contract_addr, &BOB);
+		assert_return_code!(output, PocsReturnCode::AlreadyDelegated);
+	});
+}
+
+/// Seeds a batch of contracts owned by ALICE, rejecting the second and third delegation.
+fn seed_delegate_batch() -> Vec<(AccountId32, AccountId32)> {
+	// Gets dummy wasm binary for the contracts of the batch
+	let (wasm, _) = compile_module::<Test>("dummy").unwrap();
+	// Deposit Balance for Transaction Fees
+	let _ = Balances::set_balance(&ALICE, 1_000_000);
+	let contracts: Vec<AccountId32> = (1..=4u8)
+		.map(|salt| {
+			builder::bare_instantiate(Code::Upload(wasm.clone()))
+				.salt(salt.encode())
+				.build_and_unwrap_account_id()
+		})
+		.collect();
+	DelegateRequest::<Test>::seed(&contracts[0], &ALICE, None, MIN_REPUTATION, 0);
+	DelegateRequest::<Test>::seed(&contracts[1], &ALICE, None, REPUTATION_FACTOR, 0);
+	DelegateRequest::<Test>::seed(&contracts[2], &BOB, None, MIN_REPUTATION, 0);
+	DelegateRequest::<Test>::seed(&contracts[3], &ALICE, None, MIN_REPUTATION, 0);
+	vec![
+		(contracts[0].clone(), BOB),
+		(contracts[1].clone(), BOB),
+		(contracts[2].clone(), BOB),
+		(contracts[3].clone(), CHARLIE),
+	]
+}
+
+#[test]
+fn pocs_delegate_batch_best_effort_skips_rejected_delegations() {
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		let delegations = seed_delegate_batch();
+		// Delegate the batch, skipping rejected delegations
+		assert_ok!(Pocs::delegate_batch(
+			RuntimeOrigin::signed(ALICE),
+			BoundedVec::truncate_from(delegations.clone()),
+			DelegateBatchMode::BestEffort,
+		));
+		assert_eq!(<DelegateInfo<Test>>::get(&delegations[0].0).unwrap().delegate_to(), Some(BOB));
+		assert_eq!(<DelegateInfo<Test>>::get(&delegations[1].0).unwrap().delegate_to(), None);
+		assert_eq!(<DelegateInfo<Test>>::get(&delegations[2].0).unwrap().delegate_to(), None);
+		assert_eq!(
+			<DelegateInfo<Test>>::get(&delegations[3].0).unwrap().delegate_to(),
+			Some(CHARLIE)
+		);
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), Some(1));
+		assert_eq!(ValidatorInfoMap::<Test>::get(&CHARLIE), Some(1));
+		// Check the result of every delegation is reported in order
+		assert_eq!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::Pocs(crate::Event::DelegatedBatch {
+				owner: ALICE,
+				results: vec![
+					Ok(()),
+					Err(Error::<Test>::LowReputation.into()),
+					Err(Error::<Test>::InvalidContractOwner.into()),
+					Ok(()),
+				],
+			}),
+		);
+		assert_ok!(Pocs::do_try_state());
+	});
+}
+
+#[test]
+fn pocs_delegate_batch_all_or_nothing_reverts_on_rejection() {
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		let delegations = seed_delegate_batch();
+		// The second delegation fails the batch, refunding the unprocessed delegations
+		let call = RuntimeCall::Pocs(crate::Call::delegate_batch {
+			delegations: BoundedVec::truncate_from(delegations.clone()),
+			mode: DelegateBatchMode::AllOrNothing,
+		});
+		let error = call.dispatch(RuntimeOrigin::signed(ALICE)).unwrap_err();
+		assert_eq!(error.error, Error::<Test>::LowReputation.into());
+		assert_eq!(error.post_info.actual_weight, Some(<() as WeightInfo>::delegate_batch(2)),);
+		// No delegation of the batch is applied
+		for (contract_addr, _) in &delegations {
+			let delegate_info = <DelegateInfo<Test>>::get(contract_addr).unwrap();
+			assert_eq!(delegate_info.delegate_to(), None);
+		}
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), None);
+		// A batch without rejections is applied as a whole
+		let delegations = vec![delegations[0].clone(), delegations[3].clone()];
+		assert_ok!(Pocs::delegate_batch(
+			RuntimeOrigin::signed(ALICE),
+			BoundedVec::truncate_from(delegations.clone()),
+			DelegateBatchMode::AllOrNothing,
+		));
+		assert_eq!(<DelegateInfo<Test>>::get(&delegations[0].0).unwrap().delegate_to(), Some(BOB));
+		assert_eq!(
+			<DelegateInfo<Test>>::get(&delegations[1].0).unwrap().delegate_to(),
+			Some(CHARLIE)
+		);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::Pocs(crate::Event::DelegatedBatch {
+				owner: ALICE,
+				results: vec![Ok(()), Ok(())],
+			}),
+		);
+	});
+}
+
+#[test]
+fn pocs_migration_v1_moves_storage_once_contracts_are_migrated() {
+	use crate::{
+		migration::v1::{old, MoveFromContracts},
+		ContractsStorageMoved, FailureInfoMap, PocsInfoMap,
+	};
+	use frame_support::traits::{
+		BeforeAllRuntimeMigrations, GetStorageVersion, OnIdle, OnRuntimeUpgrade, StorageVersion,
+	};
+	use pallet_contracts::migration::{v17, v18};
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		// Instantiate Contracts
+		let delegated_addr =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id();
+		let self_delegated_addr = builder::bare_instantiate(Code::Existing(code_hash))
+			.salt(vec![1])
+			.build_and_unwrap_account_id();
+		let unstaked_addr = builder::bare_instantiate(Code::Existing(code_hash))
+			.salt(vec![2])
+			.build_and_unwrap_account_id();
+		// Replace the stake with the storage of contracts pallet version 16, where the last
+		// contract predates staking, and drop this pallet as if it was added by the upgrade
+		let _ = unhashed::clear_prefix(&sp_io::hashing::twox_128(b"Pocs"), None, None);
+		let old_stake_info = v17::StakeInfo { reputation: 3, blockheight: 1, stake_score: 7 };
+		for (contract_addr, delegate_to) in [(&delegated_addr, BOB), (&self_delegated_addr, ALICE)]
+		{
+			v17::DelegateInfoMap::<Test>::insert(
+				contract_addr,
+				v17::DelegateInfo { owner: ALICE, delegate_to, delegate_at: 1 },
+			);
+			v17::StakeInfoMap::<Test>::insert(contract_addr, old_stake_info.clone());
+		}
+		old::ValidatorInfoMap::<Test>::insert(&ALICE, 1);
+		old::ValidatorInfoMap::<Test>::insert(&BOB, 2);
+		let failure_info =
+			FailureInfo::<Test>::decode(&mut &(1u64, 4u32, 2u32).encode()[..]).unwrap();
+		old::FailureInfoMap::<Test>::insert(&delegated_addr, failure_info.clone());
+		// Counts stored under the early rules, which counted a contract delegated to its owner
+		// towards the owner, and stale counts
+		old::ValidatorInfoMap::<Test>::insert(&CHARLIE, 1);
+		StorageVersion::new(16).put::<Contracts>();
+		// Upgrade the runtime in the order of `frame_executive::Executive`
+		initialize_block(2);
+		AllPalletsWithSystem::before_all_runtime_migrations();
+		<(pallet_contracts::Migration<Test, false>, AllPalletsWithSystem)>::on_runtime_upgrade();
+		// The new pallet is stamped with its in-code storage version
+		assert_eq!(Pocs::on_chain_storage_version(), crate::pallet::STORAGE_VERSION);
+		// Nothing is moved while the contracts pallet is migrating
+		Pocs::on_idle(2, Weight::MAX);
+		assert!(!ContractsStorageMoved::<Test>::get());
+		assert_eq!(old::ValidatorInfoMap::<Test>::get(&BOB), Some(2));
+		for _ in 0..10 {
+			Contracts::on_idle(2, Weight::MAX);
+		}
+		assert_eq!(Contracts::on_chain_storage_version(), StorageVersion::new(18));
+		assert_eq!(v18::PocsInfoMap::<Test>::iter_keys().count(), 3);
+		// The storage is moved in parts as long as the block has weight left
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		let limit = db_weight.reads(2).saturating_add(db_weight.reads_writes(4, 4));
+		assert_eq!(MoveFromContracts::<Test>::on_idle(limit), limit);
+		assert_eq!(v18::PocsInfoMap::<Test>::iter_keys().count(), 2);
+		assert_eq!(PocsInfoMap::<Test>::iter_keys().count(), 1);
+		assert!(!ContractsStorageMoved::<Test>::get());
+		initialize_block(3);
+		AllPalletsWithSystem::on_idle(3, Weight::MAX);
+		assert!(ContractsStorageMoved::<Test>::get());
+		// Delegations are migrated and contracts predating staking are backfilled undelegated
+		assert_eq!(<DelegateInfo<Test>>::get(&delegated_addr).unwrap().delegate_to(), Some(BOB));
+		assert_eq!(<DelegateInfo<Test>>::get(&self_delegated_addr).unwrap().delegate_to(), None);
+		let delegate_info = <DelegateInfo<Test>>::get(&unstaked_addr).unwrap();
+		assert_eq!(delegate_info.owner(), ALICE);
+		assert_eq!(delegate_info.delegate_to(), None);
+		let stake_info = <StakeInfo<Test>>::get(&delegated_addr).unwrap();
+		assert_eq!((stake_info.reputation(), stake_info.stake_score()), (3, 7));
+		assert_eq!(FailureInfoMap::<Test>::get(&delegated_addr), Some(failure_info));
+		// Validator counts are recomputed from the delegations
+		assert_eq!(ValidatorInfoMap::<Test>::get(&ALICE), None);
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), Some(1));
+		assert_eq!(ValidatorInfoMap::<Test>::get(&CHARLIE), None);
+		// The contracts pallet doesn't keep any PoCS storage
+		assert_eq!(v18::PocsInfoMap::<Test>::iter_keys().count(), 0);
+		assert_eq!(old::ValidatorInfoMap::<Test>::iter_keys().count(), 0);
+		assert_eq!(old::FailureInfoMap::<Test>::iter_keys().count(), 0);
+		assert_ok!(Pocs::do_try_state());
+		// The storage is moved once only
+		assert_eq!(Pocs::on_idle(4, Weight::MAX), db_weight.reads(2));
+	});
+}
+
+#[test]
+fn pocs_migration_v1_replaces_stake_created_while_moving() {
+	use crate::{
+		migration::v1::{old, MoveFromContracts, OldDelegateInfo, OldPocsInfo},
+		ContractsStorageMoved, PocsInfoMap,
+	};
+	// Gets dummy wasm binary for contract deployment
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	// Test Execution Interface under a closure
+	ExtBuilder::default().existential_deposit(200).build_and_execute(|| {
+		// Initialize First Block
+		initialize_block(1);
+		// Deposit Balance for Transaction Fees
+		let _ = Balances::set_balance(&ALICE, 1_000_000);
+		let _ = Balances::set_balance(&CHARLIE, 1_000_000);
+		// Instantiate Contracts
+		let contracts = [
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_account_id(),
+			builder::bare_instantiate(Code::Existing(code_hash))
+				.salt(vec![1])
+				.build_and_unwrap_account_id(),
+		];
+		// Replace the stake with the storage of the migrated contracts pallet, where both
+		// contracts are delegated to BOB
+		let _ = unhashed::clear_prefix(&sp_io::hashing::twox_128(b"Pocs"), None, None);
+		let stake_info = StakeInfo::<Test>::decode(&mut &(3u32, 1u64, 7u128).encode()[..]).unwrap();
+		for contract_addr in &contracts {
+			old::PocsInfoMap::<Test>::insert(
+				contract_addr,
+				OldPocsInfo {
+					delegate_info: OldDelegateInfo {
+						owner: ALICE,
+						delegate_to: BOB,
+						delegate_at: 1,
+					},
+					stake_info: stake_info.clone(),
+				},
+			);
+		}
+		// Move the stake of a single contract
+		initialize_block(2);
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		MoveFromContracts::<Test>::on_idle(
+			db_weight.reads(2).saturating_add(db_weight.reads_writes(4, 4)),
+		);
+		let pending_addr = old::PocsInfoMap::<Test>::iter_keys().next().unwrap();
+		assert_eq!(PocsInfoMap::<Test>::iter_keys().count(), 1);
+		assert!(!ContractsStorageMoved::<Test>::get());
+		// Calling the other contract creates fresh stake owned by the caller
+		builder::bare_call(pending_addr.clone()).origin(CHARLIE).build_and_unwrap_result();
+		assert_eq!(<DelegateInfo<Test>>::get(&pending_addr).unwrap().owner(), CHARLIE);
+		// Moving the remaining stake replaces the fresh stake
+		MoveFromContracts::<Test>::on_idle(Weight::MAX);
+		assert!(ContractsStorageMoved::<Test>::get());
+		for contract_addr in &contracts {
+			let delegate_info = <DelegateInfo<Test>>::get(contract_addr).unwrap();
+			assert_eq!(delegate_info.owner(), ALICE);
+			assert_eq!(delegate_info.delegate_to(), Some(BOB));
+			let stake_info = <StakeInfo<Test>>::get(contract_addr).unwrap();
+			assert_eq!((stake_info.reputation(), stake_info.stake_score()), (3, 7));
+		}
+		assert_eq!(ValidatorInfoMap::<Test>::get(&BOB), Some(2));
+		assert_ok!(Pocs::do_try_state());
+	});
+}
@@ -0,0 +1,69 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Seeded runs of the [fuzz driver](crate::fuzz).
+//!
+//! The inputs are derived from a seeded generator, so that the state machine is exercised by
+//! every test run without a fuzzing engine. Building with `--cfg fuzzing` runs many more and
+//! longer sequences, and the seed can be pinned through the `POCS_FUZZ_SEED` environment variable
+//! to reproduce a failure. Coverage guided fuzzing is done by the target in
+//! `pallets/pocs/fuzzer`.
+
+use crate::fuzz::{run, MAX_STEPS};
+
+#[cfg(not(fuzzing))]
+const RUNS: u64 = 16;
+#[cfg(fuzzing)]
+const RUNS: u64 = 4_096;
+
+/// Deterministic xorshift generator producing the fuzzer inputs.
+fn inputs(seed: u64) -> impl Iterator<Item = Vec<u8>> {
+	let mut state = seed.max(1);
+	let mut next = move || {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		state
+	};
+	(0..RUNS).map(move |_| {
+		let len = (next() as usize % (MAX_STEPS * 4)) + 1;
+		(0..len).map(|_| next() as u8).collect()
+	})
+}
+
+#[test]
+fn pocs_fuzz_state_machine() {
+	let seed = std::env::var("POCS_FUZZ_SEED")
+		.ok()
+		.and_then(|seed| seed.parse().ok())
+		.unwrap_or(0x5eed_0f_90c5);
+	for data in inputs(seed) {
+		run(&data);
+	}
+}
+
+#[test]
+fn pocs_fuzz_delegate_and_hand_over() {
+	// Instantiate as ALICE, try to delegate to BOB, hand the contract to BOB and terminate it
+	run(&[0, 0, 0, 6, 0, 1, 0, 0, 2, 0, 0, 1, 3, 0, 0, 1, 4, 1, 0, 5, 1]);
+}
+
+#[test]
+fn pocs_fuzz_delegate_and_undelegate() {
+	// Instantiate as ALICE, reach minimum reputation and delegate to BOB, then undelegate as BOB,
+	// ALICE and ALICE again
+	run(&[0, 0, 1, 6, 0, 1, 0, 0, 6, 0, 1, 0, 0, 2, 0, 0, 1, 7, 1, 0, 7, 0, 0, 7, 0, 0, 5, 1]);
+}
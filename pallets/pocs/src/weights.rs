@@ -0,0 +1,504 @@
+// This file is part of PoCS-Substrate.
+// Copyright (C) Auguth Research Foundation, India.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Autogenerated weights for `pallet_pocs`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 47.1.0
+//! DATE: 2026-10-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/release/substrate-aura-node
+// benchmark
+// pallet
+// --runtime
+// target/release/wbuild/substrate-aura-runtime/substrate_aura_runtime.compact.compressed.wasm
+// --genesis-builder
+// runtime
+// --pallet
+// pallet_pocs
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --wasm-execution
+// compiled
+// --heap-pages
+// 4096
+// --template
+// .maintain/frame-weight-template.hbs
+// --header
+// .maintain/HEADER-APACHE2
+// --output
+// pallets/pocs/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_pocs`.
+pub trait WeightInfo {
+	fn fetch_delegate_to() -> Weight;
+	fn fetch_delegate_at() -> Weight;
+	fn fetch_stake_score() -> Weight;
+	fn fetch_reputation() -> Weight;
+	fn fetch_owner() -> Weight;
+	fn fetch_stake_info() -> Weight;
+	fn fetch_delegate_info() -> Weight;
+	fn fetch_num_delegates() -> Weight;
+	fn fetch_can_validate() -> Weight;
+	fn fetch_params() -> Weight;
+	fn fetch_frame_stake_score() -> Weight;
+	fn delegate() -> Weight;
+	fn update_owner() -> Weight;
+	fn delegate_batch(n: u32, ) -> Weight;
+	fn undelegate() -> Weight;
+	fn delegate_request_rejected() -> Weight;
+	fn summarize(n: u32, ) -> Weight;
+	fn validate() -> Weight;
+}
+
+/// Weights for `pallet_pocs` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_delegate_to() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_503_000 picoseconds.
+		Weight::from_parts(10_311_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_delegate_at() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_708_000 picoseconds.
+		Weight::from_parts(10_209_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_stake_score() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_929_000 picoseconds.
+		Weight::from_parts(10_252_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_reputation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_718_000 picoseconds.
+		Weight::from_parts(10_229_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_607_000 picoseconds.
+		Weight::from_parts(10_217_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_stake_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_524_000 picoseconds.
+		Weight::from_parts(10_123_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_delegate_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_973_000 picoseconds.
+		Weight::from_parts(10_464_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::ValidatorInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn fetch_num_delegates() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94`
+		//  Estimated: `3509`
+		// Minimum execution time: 8_954_000 picoseconds.
+		Weight::from_parts(9_670_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::ValidatorInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn fetch_can_validate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94`
+		//  Estimated: `3509`
+		// Minimum execution time: 9_458_000 picoseconds.
+		Weight::from_parts(10_110_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	fn fetch_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 370_000 picoseconds.
+		Weight::from_parts(424_000, 0)
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_frame_stake_score() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `220`
+		//  Estimated: `3598`
+		// Minimum execution time: 10_555_000 picoseconds.
+		Weight::from_parts(11_443_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:1)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::DeploymentBondMap` (r:1 w:0)
+	/// Proof: `Pocs::DeploymentBondMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::ValidatorInfoMap` (r:2 w:2)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `6028`
+		// Minimum execution time: 46_442_000 picoseconds.
+		Weight::from_parts(48_993_000, 6028)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:1)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::DeploymentBondMap` (r:1 w:0)
+	/// Proof: `Pocs::DeploymentBondMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn update_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `220`
+		//  Estimated: `3598`
+		// Minimum execution time: 28_406_000 picoseconds.
+		Weight::from_parts(30_139_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:32 w:32)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::DeploymentBondMap` (r:32 w:0)
+	/// Proof: `Pocs::DeploymentBondMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::ValidatorInfoMap` (r:64 w:64)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn delegate_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `64 + n * (192 ±0)`
+		//  Estimated: `990 + n * (5038 ±0)`
+		// Minimum execution time: 52_694_000 picoseconds.
+		Weight::from_parts(111_118_867, 990)
+			// Standard Error: 867_216
+			.saturating_add(Weight::from_parts(40_648_564, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5038).saturating_mul(n.into()))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:1)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::ValidatorInfoMap` (r:1 w:1)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3598`
+		// Minimum execution time: 37_611_000 picoseconds.
+		Weight::from_parts(42_938_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::DeploymentBondMap` (r:1 w:0)
+	/// Proof: `Pocs::DeploymentBondMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn delegate_request_rejected() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 19_511_000 picoseconds.
+		Weight::from_parts(28_080_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `Pocs::PendingStakeMap` (r:129 w:128)
+	/// Proof: `Pocs::PendingStakeMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::PocsInfoMap` (r:128 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn summarize(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110 + n * (207 ±0)`
+		//  Estimated: `3525 + n * (2608 ±0)`
+		// Minimum execution time: 27_256_000 picoseconds.
+		Weight::from_parts(91_788_179, 3525)
+			// Standard Error: 246_122
+			.saturating_add(Weight::from_parts(11_803_471, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2608).saturating_mul(n.into()))
+	}
+	/// Storage: `Pocs::ValidatorInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn validate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94`
+		//  Estimated: `3509`
+		// Minimum execution time: 12_062_000 picoseconds.
+		Weight::from_parts(12_577_000, 3509)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_delegate_to() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_503_000 picoseconds.
+		Weight::from_parts(10_311_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_delegate_at() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_708_000 picoseconds.
+		Weight::from_parts(10_209_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_stake_score() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_929_000 picoseconds.
+		Weight::from_parts(10_252_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_reputation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_718_000 picoseconds.
+		Weight::from_parts(10_229_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_607_000 picoseconds.
+		Weight::from_parts(10_217_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_stake_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_524_000 picoseconds.
+		Weight::from_parts(10_123_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_delegate_info() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 9_973_000 picoseconds.
+		Weight::from_parts(10_464_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::ValidatorInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn fetch_num_delegates() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94`
+		//  Estimated: `3509`
+		// Minimum execution time: 8_954_000 picoseconds.
+		Weight::from_parts(9_670_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::ValidatorInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn fetch_can_validate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94`
+		//  Estimated: `3509`
+		// Minimum execution time: 9_458_000 picoseconds.
+		Weight::from_parts(10_110_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	fn fetch_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 370_000 picoseconds.
+		Weight::from_parts(424_000, 0)
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn fetch_frame_stake_score() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `220`
+		//  Estimated: `3598`
+		// Minimum execution time: 10_555_000 picoseconds.
+		Weight::from_parts(11_443_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:1)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::DeploymentBondMap` (r:1 w:0)
+	/// Proof: `Pocs::DeploymentBondMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::ValidatorInfoMap` (r:2 w:2)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `6028`
+		// Minimum execution time: 46_442_000 picoseconds.
+		Weight::from_parts(48_993_000, 6028)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:1)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::DeploymentBondMap` (r:1 w:0)
+	/// Proof: `Pocs::DeploymentBondMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn update_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `220`
+		//  Estimated: `3598`
+		// Minimum execution time: 28_406_000 picoseconds.
+		Weight::from_parts(30_139_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:32 w:32)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::DeploymentBondMap` (r:32 w:0)
+	/// Proof: `Pocs::DeploymentBondMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::ValidatorInfoMap` (r:64 w:64)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn delegate_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `64 + n * (192 ±0)`
+		//  Estimated: `990 + n * (5038 ±0)`
+		// Minimum execution time: 52_694_000 picoseconds.
+		Weight::from_parts(111_118_867, 990)
+			// Standard Error: 867_216
+			.saturating_add(Weight::from_parts(40_648_564, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5038).saturating_mul(n.into()))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:1)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::ValidatorInfoMap` (r:1 w:1)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3598`
+		// Minimum execution time: 37_611_000 picoseconds.
+		Weight::from_parts(42_938_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Pocs::PocsInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::DeploymentBondMap` (r:1 w:0)
+	/// Proof: `Pocs::DeploymentBondMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn delegate_request_rejected() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `153`
+		//  Estimated: `3598`
+		// Minimum execution time: 19_511_000 picoseconds.
+		Weight::from_parts(28_080_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `Pocs::PendingStakeMap` (r:129 w:128)
+	/// Proof: `Pocs::PendingStakeMap` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Pocs::PocsInfoMap` (r:128 w:0)
+	/// Proof: `Pocs::PocsInfoMap` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 128]`.
+	fn summarize(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `110 + n * (207 ±0)`
+		//  Estimated: `3525 + n * (2608 ±0)`
+		// Minimum execution time: 27_256_000 picoseconds.
+		Weight::from_parts(91_788_179, 3525)
+			// Standard Error: 246_122
+			.saturating_add(Weight::from_parts(11_803_471, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2608).saturating_mul(n.into()))
+	}
+	/// Storage: `Pocs::ValidatorInfoMap` (r:1 w:0)
+	/// Proof: `Pocs::ValidatorInfoMap` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn validate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94`
+		//  Estimated: `3509`
+		// Minimum execution time: 12_062_000 picoseconds.
+		Weight::from_parts(12_577_000, 3509)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+}
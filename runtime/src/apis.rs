@@ -45,7 +45,7 @@ use crate::{configs::RuntimeBlockWeights, BlockNumber, EventRecord, Hash};
 use super::{
 	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
 	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
-	Contracts,
+	Contracts, Pocs,
 };
 
 impl_runtime_apis! {
@@ -377,4 +377,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_pocs::PocsApi<Block, AccountId> for Runtime {
+		fn stake_owner(
+			address: AccountId,
+		) -> Result<AccountId, sp_runtime::DispatchError> {
+			Pocs::stake_owner(&address)
+		}
+	}
+
 }
@@ -35,21 +35,21 @@ use frame_support::{
 use frame_system::limits::{BlockLength, BlockWeights};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::H256;
 use sp_runtime::{traits::One, Perbill};
 use sp_version::RuntimeVersion;
 
 // pallet imports
 use pallet_contracts::config_preludes::{DefaultDepositLimit, DepositPerByte, DepositPerItem};
-use pallet_contracts::migration::{v15, v16};
-use pallet_contracts::stake::chain_ext::{FetchStakeInfo,UpdateDelegateInfo};
+use pallet_contracts::migration::{v15, v16, v17, v18};
+use pallet_pocs::chain_ext::{FetchStakeInfo,UpdateDelegateInfo};
 
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Balances, Block, Timestamp, BlockNumber, TransactionPayment, Hash, Nonce, PalletInfo, Runtime,
+	AccountId, Aura, Balance, Balances, Block, Timestamp, BlockNumber, TransactionPayment, Hash, Nonce, PalletInfo,
+	Pocs, RandomnessCollectiveFlip, Runtime,
 	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	System, EXISTENTIAL_DEPOSIT, HOURS, SLOT_DURATION, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -169,19 +169,28 @@ parameter_types! {
 	pub const MaxStorageKeyLen: u32 = 128;
 	pub Schedule: pallet_contracts::Schedule<Runtime> = pallet_contracts::Schedule::default();
 	pub const CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
-
+	pub const FailureWindow: BlockNumber = HOURS;
+	pub const FailureThreshold: Perbill = Perbill::from_percent(50);
+	pub const CodeChangeReputationRetained: Perbill = Perbill::from_percent(0);
+	pub const CodeChangeStakeRetained: Perbill = Perbill::from_percent(0);
+	pub const DeploymentBondDecay: BlockNumber = 24 * HOURS;
+	pub const StakeEventMode: pallet_pocs::stake::StakeEventMode =
+		pallet_pocs::stake::StakeEventMode::PerBlock;
 }
 
-pub struct DummyRandomness;
-impl frame_support::traits::Randomness<H256, BlockNumber> for DummyRandomness {
-    fn random(_subject: &[u8]) -> (H256, BlockNumber) {
-        (Default::default(), 0)
-    }
-}
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+/// The randomness exposed to contracts, e.g. for sampling validators.
+///
+/// Collective flip derives it from the hashes of the last 81 blocks, which block authors can bias
+/// by withholding or reordering their blocks. It is only meant for development and demo chains;
+/// production runtimes must replace it with a source authors can't influence, such as a VRF or a
+/// commit-reveal beacon.
+pub type ContractsRandomness = RandomnessCollectiveFlip;
 
 impl pallet_contracts::Config for Runtime{
 	type Time = Timestamp;
-	type Randomness = DummyRandomness;
+	type Randomness = ContractsRandomness;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -210,12 +219,32 @@ impl pallet_contracts::Config for Runtime{
 	type Migrations = (
 		v15::Migration<Runtime>,
 		v16::Migration<Runtime>,
+		v17::Migration<Runtime>,
+		v18::Migration<Runtime>,
 	);
 	type Debug = ();
 	type Environment = ();
 	type ApiVersion = ();
 	type Xcm = (); 
+	type OnContractExecution = Pocs;
+	type ContractStake = Pocs;
+}
 
+impl pallet_pocs::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type FailureWindow = FailureWindow;
+	type FailureThreshold = FailureThreshold;
+	type FailurePenalty = ConstU32<1>;
+	type CodeChangeReputationRetained = CodeChangeReputationRetained;
+	type CodeChangeStakeRetained = CodeChangeStakeRetained;
+	type DeploymentBondEnabled = ConstBool<false>;
+	type DeploymentBondDecay = DeploymentBondDecay;
+	type DeploymentBondMaturity = ConstU128<0>;
+	type StakeOwnerIsOrigin = ConstBool<false>;
+	type StakeEventMode = StakeEventMode;
+	type MaxDelegateBatch = ConstU32<32>;
+	type MaxStakeSummary = ConstU32<128>;
+	type WeightInfo = pallet_pocs::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure the pallet-template in pallets/template.
@@ -15,7 +15,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig};
+use crate::{
+	AccountId, BalancesConfig, ContractsConfig, PocsConfig, Runtime, RuntimeGenesisConfig, SudoConfig,
+};
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
 use serde_json::Value;
@@ -23,6 +25,7 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_genesis_builder::{self, PresetId};
 use sp_keyring::Sr25519Keyring;
+use sp_runtime::traits::Hash;
 
 // Returns the genesis config presets populated with given parameters.
 fn testnet_genesis(
@@ -86,11 +89,50 @@ pub fn local_config_genesis() -> Value {
 	)
 }
 
+/// Name of the PoCS demo preset.
+pub const POCS_DEMO_RUNTIME_PRESET: &str = "pocs-demo";
+
+/// Number of contracts delegated to the validator of the PoCS demo preset.
+const POCS_DEMO_DELEGATES: u8 = 10;
+
+/// Code of the contracts deployed by the PoCS demo preset, a contract without any logic.
+///
+/// It's the `dummy` fixture of `pallet-contracts-fixtures` compiled to wasm.
+const POCS_DEMO_CODE: &[u8] = include_bytes!("../res/pocs_demo.wasm");
+
+/// Return the PoCS demo genesis config preset.
+///
+/// Extends the development config with contracts deployed by Alice and delegated to Bob, so
+/// that Bob is eligible to validate from the first block on.
+pub fn pocs_demo_config_genesis() -> Value {
+	let owner = Sr25519Keyring::Alice.to_account_id();
+	let validator = Sr25519Keyring::Bob.to_account_id();
+	let code_hash = <Runtime as frame_system::Config>::Hashing::hash(POCS_DEMO_CODE);
+	let contracts = (0..POCS_DEMO_DELEGATES)
+		.map(|i| (owner.clone(), POCS_DEMO_CODE.to_vec(), vec![], vec![i], 0))
+		.collect::<Vec<_>>();
+	let stakes = contracts
+		.iter()
+		.map(|(deployer, _, data, salt, _)| {
+			let contract =
+				pallet_contracts::Pallet::<Runtime>::contract_address(deployer, &code_hash, data, salt);
+			(contract, owner.clone(), Some(validator.clone()), 3, 0)
+		})
+		.collect::<Vec<_>>();
+	let mut patch = development_config_genesis();
+	patch["contracts"] = serde_json::to_value(ContractsConfig { contracts })
+		.expect("serialization to json is expected to work. qed.");
+	patch["pocs"] = serde_json::to_value(PocsConfig { stakes })
+		.expect("serialization to json is expected to work. qed.");
+	patch
+}
+
 /// Provides the JSON representation of predefined genesis config for given `id`.
 pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
 	let patch = match id.as_ref() {
 		sp_genesis_builder::DEV_RUNTIME_PRESET => development_config_genesis(),
 		sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => local_config_genesis(),
+		POCS_DEMO_RUNTIME_PRESET => pocs_demo_config_genesis(),
 		_ => return None,
 	};
 	Some(
@@ -105,5 +147,6 @@ pub fn preset_names() -> Vec<PresetId> {
 	vec![
 		PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
 		PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+		PresetId::from(POCS_DEMO_RUNTIME_PRESET),
 	]
 }
@@ -0,0 +1,94 @@
+// This is free and unencumbered software released into the public domain.
+//
+// Anyone is free to copy, modify, publish, use, compile, sell, or
+// distribute this software, either in source code form or as a compiled
+// binary, for any purpose, commercial or non-commercial, and by any
+// means.
+//
+// In jurisdictions that recognize copyright laws, the author or authors
+// of this software dedicate any and all copyright interest in the
+// software to the public domain. We make this dedication for the benefit
+// of the public at large and to the detriment of our heirs and
+// successors. We intend this dedication to be an overt act of
+// relinquishment in perpetuity of all present and future rights to this
+// software under copyright law.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+// IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY CLAIM, DAMAGES OR
+// OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+// ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+// OTHER DEALINGS IN THE SOFTWARE.
+//
+// For more information, please refer to <http://unlicense.org>
+
+use crate::{
+	genesis_config_presets, BuildStorage, Contracts, RandomnessCollectiveFlip, Runtime,
+	RuntimeGenesisConfig, System,
+};
+use frame_support::traits::{OnInitialize, Randomness};
+use serde_json::Value;
+use sp_core::H256;
+use sp_keyring::Sr25519Keyring;
+
+/// Applies the JSON `patch` to `base`, the way the genesis builder applies presets.
+fn merge(base: &mut Value, patch: Value) {
+	match (base, patch) {
+		(Value::Object(base), Value::Object(patch)) =>
+			for (key, value) in patch {
+				merge(base.entry(key).or_insert(Value::Null), value);
+			},
+		(base, patch) => *base = patch,
+	}
+}
+
+/// Builds the externalities of the genesis preset `patch`.
+fn new_test_ext(patch: Value) -> sp_io::TestExternalities {
+	let mut config = serde_json::to_value(RuntimeGenesisConfig::default())
+		.expect("serialization to json is expected to work. qed.");
+	merge(&mut config, patch);
+	serde_json::from_value::<RuntimeGenesisConfig>(config)
+		.expect("preset is a valid genesis config")
+		.build_storage()
+		.expect("preset builds")
+		.into()
+}
+
+#[test]
+fn pocs_demo_preset_only_stakes_deployed_contracts() {
+	new_test_ext(genesis_config_presets::pocs_demo_config_genesis()).execute_with(|| {
+		let contracts = pallet_pocs::PocsInfoMap::<Runtime>::iter_keys().collect::<Vec<_>>();
+		assert!(!contracts.is_empty());
+		for contract in &contracts {
+			assert!(Contracts::code_hash(contract).is_some(), "{contract:?} is no contract");
+		}
+		// Every staked contract is delegated to Bob
+		assert_eq!(
+			pallet_pocs::ValidatorInfoMap::<Runtime>::get(Sr25519Keyring::Bob.to_account_id()),
+			Some(contracts.len() as u32),
+		);
+	});
+}
+
+#[test]
+fn contracts_randomness_varies_per_block_and_subject() {
+	type ContractsRandomness = <Runtime as pallet_contracts::Config>::Randomness;
+
+	new_test_ext(genesis_config_presets::development_config_genesis()).execute_with(|| {
+		let mut outputs = Vec::new();
+		for block in 1..=3u32 {
+			// Each block records a distinct parent hash as random material
+			System::initialize(&block, &H256::repeat_byte(block as u8), &Default::default());
+			RandomnessCollectiveFlip::on_initialize(block);
+
+			let (first, _) = ContractsRandomness::random(b"first");
+			let (second, _) = ContractsRandomness::random(b"second");
+			assert_ne!(first, H256::zero());
+			assert_ne!(first, second);
+			outputs.push(first);
+		}
+		assert_ne!(outputs[0], outputs[1]);
+		assert_ne!(outputs[1], outputs[2]);
+	});
+}